@@ -0,0 +1,302 @@
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::{spanned::Spanned, Attribute, Expr, Lit, Meta};
+
+// Configuration parsed from `#[form_type(...)]` attributes
+#[derive(Default)]
+struct FormTypeConfigurationParser {
+    input: Option<String>,
+    required: Option<bool>,
+    minlength: Option<usize>,
+    maxlength: Option<usize>,
+    pattern: Option<(String, proc_macro2::Span)>,
+    validate: Option<Expr>,
+}
+
+impl FormTypeConfigurationParser {
+    fn parse_from_attributes(attrs: &[Attribute]) -> Self {
+        let mut config = Self::default();
+
+        for attr in attrs {
+            if !attr.path().is_ident("form_type") {
+                continue;
+            }
+
+            if let Meta::List(meta_list) = &attr.meta {
+                let parsed = meta_list.parse_args_with(|input: syn::parse::ParseStream| {
+                    let mut pairs = Vec::new();
+
+                    while !input.is_empty() {
+                        let name: syn::Ident = input.parse()?;
+                        input.parse::<syn::Token![=]>()?;
+                        let value: syn::Expr = input.parse()?;
+                        pairs.push((name, value));
+
+                        // Handle optional comma
+                        if input.peek(syn::Token![,]) {
+                            input.parse::<syn::Token![,]>()?;
+                        }
+                    }
+
+                    Ok(pairs)
+                });
+
+                let pairs = parsed.expect("Invalid form_type attribute");
+
+                for (name, value) in pairs {
+                    match name.to_string().as_str() {
+                        "input" => config.input = Some(Self::parse_str(&value, "input")),
+                        "required" => config.required = Some(Self::parse_bool(&value, "required")),
+                        "minlength" => config.minlength = Some(Self::parse_usize(&value, "minlength")),
+                        "maxlength" => config.maxlength = Some(Self::parse_usize(&value, "maxlength")),
+                        "pattern" => config.pattern = Some((Self::parse_str(&value, "pattern"), value.span())),
+                        "validate" => config.validate = Some(value),
+                        other => panic!("Unknown form_type attribute: {}", other),
+                    }
+                }
+            }
+        }
+
+        config
+    }
+
+    fn parse_str(value: &Expr, attr: &str) -> String {
+        if let Expr::Lit(expr_lit) = value {
+            if let Lit::Str(lit_str) = &expr_lit.lit {
+                return lit_str.value();
+            }
+        }
+        panic!("Attribute `{}` expects a string literal", attr);
+    }
+
+    fn parse_bool(value: &Expr, attr: &str) -> bool {
+        if let Expr::Lit(expr_lit) = value {
+            if let Lit::Bool(lit_bool) = &expr_lit.lit {
+                return lit_bool.value;
+            }
+        }
+        panic!("Attribute `{}` expects a boolean literal", attr);
+    }
+
+    fn parse_usize(value: &Expr, attr: &str) -> usize {
+        if let Expr::Lit(expr_lit) = value {
+            if let Lit::Int(lit_int) = &expr_lit.lit {
+                if let Ok(value) = lit_int.base10_parse::<usize>() {
+                    return value;
+                }
+            }
+        }
+        panic!("Attribute `{}` expects an integer literal", attr);
+    }
+
+    fn input_type(&self) -> proc_macro2::TokenStream {
+        let variant = match self.input.as_deref().unwrap_or("text") {
+            "text" => quote! { Text },
+            "email" => quote! { Email },
+            "password" => quote! { Password },
+            "color" => quote! { Color },
+            "date" => quote! { Date },
+            "time" => quote! { Time },
+            "datetime-local" => quote! { DatetimeLocal },
+            "number" => quote! { Number },
+            "tel" => quote! { Tel },
+            "url" => quote! { Url },
+            "range" => quote! { Range },
+            other => panic!("Unsupported input type: {}", other),
+        };
+
+        quote! { formidable::components::InputType::#variant }
+    }
+}
+
+fn is_string_type(ty: &syn::Type) -> bool {
+    if let syn::Type::Path(type_path) = ty {
+        if let Some(segment) = type_path.path.segments.last() {
+            return segment.ident == "String" && segment.arguments.is_empty();
+        }
+    }
+    false
+}
+
+pub(crate) fn impl_form_type_macro(ast: &syn::DeriveInput) -> TokenStream {
+    let name = &ast.ident;
+    let vis = &ast.vis;
+
+    let inner_type = match &ast.data {
+        syn::Data::Struct(syn::DataStruct {
+            fields: syn::Fields::Unnamed(fields),
+            ..
+        }) if fields.unnamed.len() == 1 => &fields.unnamed.first().unwrap().ty,
+        _ => panic!("FormType can only be derived for tuple structs with a single field"),
+    };
+
+    let config = FormTypeConfigurationParser::parse_from_attributes(&ast.attrs);
+    let error_name = format_ident!("{}Error", name);
+    let input_type = config.input_type();
+
+    // Inner types other than `String` are parsed via their own `FromStr`,
+    // whose errors are reported through the `Invalid` variant.
+    let parses_inner = !is_string_type(inner_type);
+    let has_invalid_variant = parses_inner || config.validate.is_some();
+
+    let mut error_variants = Vec::new();
+    let mut error_messages = Vec::new();
+    let mut checks = Vec::new();
+    // Checks of the format, which an empty input of an optional type skips
+    let mut format_checks = Vec::new();
+
+    if config.required == Some(true) {
+        error_variants.push(quote! { Required });
+        error_messages.push(quote! { #error_name::Required => write!(f, "This field is required") });
+        checks.push(quote! {
+            if s.is_empty() {
+                return Err(#error_name::Required);
+            }
+        });
+    }
+
+    if let Some(minlength) = config.minlength {
+        error_variants.push(quote! { TooShort });
+        error_messages.push(quote! {
+            #error_name::TooShort => write!(f, "Must be at least {} characters long", #minlength)
+        });
+        format_checks.push(quote! {
+            if s.chars().count() < #minlength {
+                return Err(#error_name::TooShort);
+            }
+        });
+    }
+
+    if let Some(maxlength) = config.maxlength {
+        error_variants.push(quote! { TooLong });
+        error_messages.push(quote! {
+            #error_name::TooLong => write!(f, "Must be at most {} characters long", #maxlength)
+        });
+        format_checks.push(quote! {
+            if s.chars().count() > #maxlength {
+                return Err(#error_name::TooLong);
+            }
+        });
+    }
+
+    if let Some((pattern, span)) = &config.pattern {
+        // The HTML `pattern` attribute is implicitly anchored, do the same here
+        let anchored = format!("^(?:{})$", pattern);
+        if let Err(err) = regex_syntax::parse(&anchored) {
+            let message = format!("Invalid pattern: {}", err);
+            return syn::Error::new(*span, message).to_compile_error().into();
+        }
+        error_variants.push(quote! { InvalidFormat });
+        error_messages.push(quote! { #error_name::InvalidFormat => write!(f, "Invalid format") });
+        format_checks.push(quote! {
+            if !formidable::__form_type_pattern!(#anchored).is_match(s) {
+                return Err(#error_name::InvalidFormat);
+            }
+        });
+    }
+
+    if !format_checks.is_empty() {
+        checks.push(quote! {
+            if !s.is_empty() {
+                #(#format_checks)*
+            }
+        });
+    }
+
+    if has_invalid_variant {
+        error_variants.push(quote! { Invalid(String) });
+        error_messages.push(quote! { #error_name::Invalid(message) => write!(f, "{}", message) });
+    }
+
+    // Types without any checks get an uninhabited error enum
+    let display_error = if error_messages.is_empty() {
+        quote! { match *self {} }
+    } else {
+        quote! {
+            match self {
+                #(#error_messages),*
+            }
+        }
+    };
+
+    if let Some(validate) = &config.validate {
+        checks.push(quote! {
+            (#validate)(s).map_err(|err| #error_name::Invalid(err.to_string()))?;
+        });
+    }
+
+    let construct = if parses_inner {
+        quote! {
+            s.parse::<#inner_type>()
+                .map(#name)
+                .map_err(|err| #error_name::Invalid(err.to_string()))
+        }
+    } else {
+        quote! { Ok(#name(s.to_string())) }
+    };
+
+    let required = match config.required {
+        Some(required) => quote! { Some(#required) },
+        None => quote! { None },
+    };
+    let minlength = match config.minlength {
+        Some(minlength) => quote! { Some(#minlength) },
+        None => quote! { None },
+    };
+    let maxlength = match config.maxlength {
+        Some(maxlength) => quote! { Some(#maxlength) },
+        None => quote! { None },
+    };
+    let pattern = match &config.pattern {
+        Some((pattern, _)) => quote! { Some(#pattern) },
+        None => quote! { None },
+    };
+
+    let generated = quote! {
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        #vis enum #error_name {
+            #(#error_variants),*
+        }
+
+        impl std::fmt::Display for #error_name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                #display_error
+            }
+        }
+
+        impl std::error::Error for #error_name {}
+
+        impl std::str::FromStr for #name {
+            type Err = #error_name;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                #(#checks)*
+                #construct
+            }
+        }
+
+        impl std::convert::TryFrom<String> for #name {
+            type Error = #error_name;
+
+            fn try_from(value: String) -> Result<Self, Self::Error> {
+                value.parse()
+            }
+        }
+
+        impl std::fmt::Display for #name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+
+        impl formidable::types::FormType for #name {
+            const INPUT_TYPE: formidable::components::InputType = #input_type;
+            const REQUIRED: Option<bool> = #required;
+            const MIN_LENGTH: Option<usize> = #minlength;
+            const MAX_LENGTH: Option<usize> = #maxlength;
+            const PATTERN: Option<&'static str> = #pattern;
+        }
+    };
+
+    generated.into()
+}
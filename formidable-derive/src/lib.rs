@@ -1,5 +1,7 @@
 use core::panic;
 
+mod form_type;
+
 use proc_macro::TokenStream;
 use quote::quote;
 use syn::{Attribute, Meta, MetaNameValue, Expr, Lit};
@@ -10,6 +12,12 @@ pub fn my_proc_macro(input: TokenStream) -> TokenStream {
     impl_form_macro(&ast)
 }
 
+#[proc_macro_derive(FormType, attributes(form_type))]
+pub fn form_type_proc_macro(input: TokenStream) -> TokenStream {
+    let ast = syn::parse(input).unwrap();
+    form_type::impl_form_type_macro(&ast)
+}
+
 // Helper function to create String from expression (for top-level generation)
 fn create_string_from_expr(expr: &Expr) -> proc_macro2::TokenStream {
     match expr {
@@ -47,6 +55,18 @@ struct FieldConfigurationParser {
     columns: Option<u32>,
     colspan: Option<u32>,
     placeholder: Option<String>,
+    review: Option<bool>,
+    validation_mode: Option<String>,
+    presence: Option<String>,
+    min_items: Option<usize>,
+    max_items: Option<usize>,
+    item_label: Option<Expr>,
+    min_selected: Option<usize>,
+    max_selected: Option<usize>,
+    validate: Option<Expr>,
+    async_validate: Option<Expr>,
+    debounce: Option<u64>,
+    name: Option<String>,
 }
 
 impl FieldConfigurationParser {
@@ -119,6 +139,82 @@ impl FieldConfigurationParser {
                                             }
                                         }
                                     },
+                                    "review" => {
+                                        if let Expr::Lit(expr_lit) = &value {
+                                            if let Lit::Bool(lit_bool) = &expr_lit.lit {
+                                                config.review = Some(lit_bool.value);
+                                            }
+                                        }
+                                    },
+                                    "validation_mode" => {
+                                        if let Expr::Lit(expr_lit) = &value {
+                                            if let Lit::Str(lit_str) = &expr_lit.lit {
+                                                config.validation_mode = Some(lit_str.value());
+                                            }
+                                        }
+                                    },
+                                    "presence" => {
+                                        if let Expr::Lit(expr_lit) = &value {
+                                            if let Lit::Str(lit_str) = &expr_lit.lit {
+                                                config.presence = Some(lit_str.value());
+                                            }
+                                        }
+                                    },
+                                    "min_items" => {
+                                        if let Expr::Lit(expr_lit) = &value {
+                                            if let Lit::Int(lit_int) = &expr_lit.lit {
+                                                if let Ok(min_items) = lit_int.base10_parse::<usize>() {
+                                                    config.min_items = Some(min_items);
+                                                }
+                                            }
+                                        }
+                                    },
+                                    "max_items" => {
+                                        if let Expr::Lit(expr_lit) = &value {
+                                            if let Lit::Int(lit_int) = &expr_lit.lit {
+                                                if let Ok(max_items) = lit_int.base10_parse::<usize>() {
+                                                    config.max_items = Some(max_items);
+                                                }
+                                            }
+                                        }
+                                    },
+                                    "min_selected" => {
+                                        if let Expr::Lit(expr_lit) = &value {
+                                            if let Lit::Int(lit_int) = &expr_lit.lit {
+                                                if let Ok(min_selected) = lit_int.base10_parse::<usize>() {
+                                                    config.min_selected = Some(min_selected);
+                                                }
+                                            }
+                                        }
+                                    },
+                                    "max_selected" => {
+                                        if let Expr::Lit(expr_lit) = &value {
+                                            if let Lit::Int(lit_int) = &expr_lit.lit {
+                                                if let Ok(max_selected) = lit_int.base10_parse::<usize>() {
+                                                    config.max_selected = Some(max_selected);
+                                                }
+                                            }
+                                        }
+                                    },
+                                    "name" => {
+                                        if let Expr::Lit(expr_lit) = &value {
+                                            if let Lit::Str(lit_str) = &expr_lit.lit {
+                                                config.name = Some(lit_str.value());
+                                            }
+                                        }
+                                    },
+                                    "item_label" => config.item_label = Some(value),
+                                    "validate" => config.validate = Some(value),
+                                    "async_validate" => config.async_validate = Some(value),
+                                    "debounce" => {
+                                        if let Expr::Lit(expr_lit) = &value {
+                                            if let Lit::Int(lit_int) = &expr_lit.lit {
+                                                if let Ok(debounce) = lit_int.base10_parse::<u64>() {
+                                                    config.debounce = Some(debounce);
+                                                }
+                                            }
+                                        }
+                                    },
                                     _ => {} // Ignore unknown attributes
                                 }
                             }
@@ -164,6 +260,76 @@ impl FieldConfigurationParser {
                                     config.placeholder = Some(lit_str.value());
                                 }
                             }
+                        } else if path.is_ident("review") {
+                            if let Expr::Lit(expr_lit) = value {
+                                if let Lit::Bool(lit_bool) = &expr_lit.lit {
+                                    config.review = Some(lit_bool.value);
+                                }
+                            }
+                        } else if path.is_ident("name") {
+                            if let Expr::Lit(expr_lit) = value {
+                                if let Lit::Str(lit_str) = &expr_lit.lit {
+                                    config.name = Some(lit_str.value());
+                                }
+                            }
+                        } else if path.is_ident("item_label") {
+                            config.item_label = Some(value.clone());
+                        } else if path.is_ident("validate") {
+                            config.validate = Some(value.clone());
+                        } else if path.is_ident("async_validate") {
+                            config.async_validate = Some(value.clone());
+                        } else if path.is_ident("debounce") {
+                            if let Expr::Lit(expr_lit) = value {
+                                if let Lit::Int(lit_int) = &expr_lit.lit {
+                                    if let Ok(debounce) = lit_int.base10_parse::<u64>() {
+                                        config.debounce = Some(debounce);
+                                    }
+                                }
+                            }
+                        } else if path.is_ident("validation_mode") {
+                            if let Expr::Lit(expr_lit) = value {
+                                if let Lit::Str(lit_str) = &expr_lit.lit {
+                                    config.validation_mode = Some(lit_str.value());
+                                }
+                            }
+                        } else if path.is_ident("presence") {
+                            if let Expr::Lit(expr_lit) = value {
+                                if let Lit::Str(lit_str) = &expr_lit.lit {
+                                    config.presence = Some(lit_str.value());
+                                }
+                            }
+                        } else if path.is_ident("min_items") {
+                            if let Expr::Lit(expr_lit) = value {
+                                if let Lit::Int(lit_int) = &expr_lit.lit {
+                                    if let Ok(min_items) = lit_int.base10_parse::<usize>() {
+                                        config.min_items = Some(min_items);
+                                    }
+                                }
+                            }
+                        } else if path.is_ident("max_items") {
+                            if let Expr::Lit(expr_lit) = value {
+                                if let Lit::Int(lit_int) = &expr_lit.lit {
+                                    if let Ok(max_items) = lit_int.base10_parse::<usize>() {
+                                        config.max_items = Some(max_items);
+                                    }
+                                }
+                            }
+                        } else if path.is_ident("min_selected") {
+                            if let Expr::Lit(expr_lit) = value {
+                                if let Lit::Int(lit_int) = &expr_lit.lit {
+                                    if let Ok(min_selected) = lit_int.base10_parse::<usize>() {
+                                        config.min_selected = Some(min_selected);
+                                    }
+                                }
+                            }
+                        } else if path.is_ident("max_selected") {
+                            if let Expr::Lit(expr_lit) = value {
+                                if let Lit::Int(lit_int) = &expr_lit.lit {
+                                    if let Ok(max_selected) = lit_int.base10_parse::<usize>() {
+                                        config.max_selected = Some(max_selected);
+                                    }
+                                }
+                            }
                         }
                     },
                     _ => {} // Ignore other meta types
@@ -175,7 +341,9 @@ impl FieldConfigurationParser {
     }
     
 
-    fn to_field_configuration(&self) -> proc_macro2::TokenStream {       
+    /// The `FieldConfiguration` of a field of type `field_type`, or of an enum variant without a
+    /// single field
+    fn to_field_configuration(&self, field_type: Option<&syn::Type>) -> proc_macro2::TokenStream {
         let label =  create_string_from_expr(self.label.as_ref().expect("Label is required"));
         let label = quote! { leptos::prelude::TextProp::from(#label) };
 
@@ -204,6 +372,54 @@ impl FieldConfigurationParser {
             quote! { None }
         };
 
+        let validation_mode = if let Some(validation_mode) = &self.validation_mode {
+            let variant = match validation_mode.as_str() {
+                "on_input" => quote! { OnInput },
+                "on_blur" => quote! { OnBlur },
+                "on_submit" => quote! { OnSubmit },
+                "on_blur_then_input" => quote! { OnBlurThenInput },
+                other => panic!("Unsupported validation mode: {}", other),
+            };
+            quote! { Some(formidable::ValidationMode::#variant) }
+        } else {
+            quote! { None }
+        };
+
+        let presence = if let Some(presence) = &self.presence {
+            let variant = match presence.as_str() {
+                "checkbox" => quote! { Checkbox },
+                "empty_is_none" => quote! { EmptyIsNone },
+                "button" => quote! { Button },
+                other => panic!("Unsupported presence: {}", other),
+            };
+            quote! { Some(formidable::Presence::#variant) }
+        } else {
+            quote! { None }
+        };
+
+        let min_items = match self.min_items {
+            Some(min_items) => quote! { Some(#min_items) },
+            None => quote! { None },
+        };
+
+        let max_items = match self.max_items {
+            Some(max_items) => quote! { Some(#max_items) },
+            None => quote! { None },
+        };
+
+        // A template string, or a closure over the position and value of the item
+        let item_label = match &self.item_label {
+            Some(Expr::Lit(expr_lit)) if matches!(expr_lit.lit, Lit::Str(_)) => {
+                quote! { Some(formidable::ItemLabel::Template(String::from(#expr_lit))) }
+            }
+            // The closure is checked against the item type of the field at compile time
+            Some(item_label) => match field_type {
+                Some(field_type) => quote! { Some(formidable::ItemLabel::function::<#field_type>(#item_label)) },
+                None => panic!("item_label closures are only supported on list fields"),
+            },
+            None => quote! { None },
+        };
+
         quote! {
             formidable::FieldConfiguration {
                 label: Some(#label),
@@ -211,6 +427,11 @@ impl FieldConfigurationParser {
                 class: #class,
                 colspan: #colspan,
                 placeholder: #placeholder,
+                validation_mode: #validation_mode,
+                presence: #presence,
+                min_items: #min_items,
+                max_items: #max_items,
+                item_label: #item_label,
             }
         }
     }
@@ -219,6 +440,112 @@ impl FieldConfigurationParser {
         let label = self.label.as_ref().expect("Label is required");
         create_string_from_expr(label)
     }
+
+    /// The `SelectionConfig` of a field rendered as a multi-select, if it has any of the
+    /// selection attributes
+    fn selection_config(&self) -> Option<proc_macro2::TokenStream> {
+        let render_as = match self.render_as.as_deref() {
+            Some("checkboxes") => quote! { Checkboxes },
+            Some("select_multiple") => quote! { SelectMultiple },
+            Some("table" | "slider") => return None,
+            Some(other) => panic!("Unsupported render_as type for field: {}. Supported values are 'checkboxes', 'select_multiple', 'table' and 'slider'", other),
+            None if self.min_selected.is_some() || self.max_selected.is_some() => quote! { Checkboxes },
+            None => return None,
+        };
+        let min = match self.min_selected {
+            Some(min) => quote! { Some(#min) },
+            None => quote! { None },
+        };
+        let max = match self.max_selected {
+            Some(max) => quote! { Some(#max) },
+            None => quote! { None },
+        };
+
+        Some(quote! {
+            formidable::types::SelectionConfig {
+                render_as: formidable::types::SelectionMode::#render_as,
+                min: #min,
+                max: #max,
+            }
+        })
+    }
+
+    /// The validation of the number of items of a `Vec` field, if it has `min_items` or `max_items`
+    fn item_count_validation(&self, field_value: &proc_macro2::TokenStream, name: &proc_macro2::TokenStream) -> Option<proc_macro2::TokenStream> {
+        if self.min_items.is_none() && self.max_items.is_none() {
+            return None;
+        }
+        let min_items = match self.min_items {
+            Some(min_items) => quote! { Some(#min_items) },
+            None => quote! { None },
+        };
+        let max_items = match self.max_items {
+            Some(max_items) => quote! { Some(#max_items) },
+            None => quote! { None },
+        };
+
+        Some(quote! {
+            formidable::types::validate_item_count((#field_value).len(), &(#name), #min_items, #max_items)
+        })
+    }
+
+    /// The call rendering a field of type `field_type`
+    fn view_call(
+        &self,
+        field_type: &syn::Type,
+        field_configuration: &proc_macro2::TokenStream,
+        name: proc_macro2::TokenStream,
+        value: proc_macro2::TokenStream,
+        callback: proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        match self.render_as.as_deref() {
+            Some("table") => return quote! {
+                formidable::types::view_table(#field_configuration, #name, #value, #callback)
+            },
+            // A slider is never empty, so it cannot be `None` when empty
+            Some("slider") if self.presence.as_deref() == Some("empty_is_none") => return quote! {
+                compile_error!("`presence = \"empty_is_none\"` is not supported with `render_as = \"slider\"`, use `presence = \"checkbox\"` or `presence = \"button\"`")
+            },
+            Some("slider") => return quote! {
+                <#field_type as formidable::types::Slider>::view_slider(#field_configuration, #name, #value, #callback)
+            },
+            _ => {}
+        }
+
+        match self.selection_config() {
+            Some(selection_config) => quote! {
+                formidable::types::view_selection::<#field_type, _>(#field_configuration, #name, #value, #callback, #selection_config)
+            },
+            // Only an input can be empty, so the value must be rendered as a single input
+            None if self.presence.as_deref() == Some("empty_is_none") => quote! {
+                {
+                    let () = <#field_type as formidable::types::EmptyIsNone>::ASSERT_SINGLE_INPUT;
+                    <#field_type as Form>::view(#field_configuration, #name, #value, #callback)
+                }
+            },
+            None => quote! {
+                <#field_type as Form>::view(#field_configuration, #name, #value, #callback)
+            },
+        }
+    }
+
+    /// The call rendering the read-only summary of a field of type `field_type`
+    fn view_summary_call(
+        &self,
+        field_type: &syn::Type,
+        field_configuration: &proc_macro2::TokenStream,
+        name: proc_macro2::TokenStream,
+        value: proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        match self.selection_config() {
+            Some(_) => quote! {
+                formidable::types::view_selection_summary::<#field_type>(#field_configuration, #name, #value)
+            },
+            None => quote! {
+                <#field_type as Form>::view_summary(#field_configuration, #name, #value)
+            },
+        }
+    }
 }
 
 // Shared field processing logic to eliminate duplication
@@ -287,8 +614,43 @@ impl FieldProcessor {
             let field_type = &field.ty;
             let form_config = FieldConfigurationParser::parse_from_attributes(&field.attrs);
             let signal_name = quote::format_ident!("{}_signal", field_name);
-            let field_configuration = form_config.to_field_configuration();
+            let field_configuration = form_config.to_field_configuration(Some(field_type));
+            let view_call = form_config.view_call(
+                field_type,
+                &field_configuration,
+                quote! { field_name_as_name },
+                quote! { field_value },
+                quote! { field_callback },
+            );
             
+            if let Some(async_validate) = &form_config.async_validate {
+                let debounce = if let Some(debounce) = form_config.debounce {
+                    quote! { std::time::Duration::from_millis(#debounce) }
+                } else {
+                    quote! { formidable::components::AsyncValidator::<#field_type>::DEFAULT_DEBOUNCE }
+                };
+
+                // Values that passed the synchronous validation are checked by the async validator
+                return quote! {
+                    {
+                        let field_name_as_name = name.push_key(#field_name_str);
+                        let field_value = #signal_name.get_untracked().and_then(|r| r.ok());
+                        let async_validator = formidable::components::AsyncValidator::<#field_type>::new(field_name_as_name.clone(), #debounce, #async_validate);
+                        let set_field = leptos::prelude::Callback::new(move |result: Result<#field_type, formidable::FormError>| {
+                            #signal_name.set(Some(result));
+                        });
+                        let field_callback = Some(leptos::prelude::Callback::new(move |result: Result<#field_type, formidable::FormError>| {
+                            async_validator.validate(result, set_field);
+                        }));
+
+                        (
+                            #view_call,
+                            view! { <formidable::components::ValidationPending pending=async_validator.pending() error=async_validator.error() /> },
+                        )
+                    }
+                };
+            }
+
             quote! {
                 {
                     let field_name_as_name = name.push_key(#field_name_str);
@@ -297,28 +659,183 @@ impl FieldProcessor {
                         #signal_name.set(Some(result));
                     }));
                     
-                    <#field_type as Form>::view(
-                        #field_configuration,
-                        field_name_as_name, 
-                        field_value, 
-                        field_callback
-                    )
+                    #view_call
                 }
             }
         }).collect()
     }
     
+    /// Generate read-only summary UI elements, reading each field from `value_expr`
+    fn generate_field_summaries(
+        fields: &syn::punctuated::Punctuated<syn::Field, syn::token::Comma>,
+        value_expr: impl Fn(&syn::Ident) -> proc_macro2::TokenStream,
+    ) -> Vec<proc_macro2::TokenStream> {
+        fields.iter().map(|field| {
+            let field_name = field.ident.as_ref().unwrap();
+            let field_name_str = field_name.to_string();
+            let field_type = &field.ty;
+            let form_config = FieldConfigurationParser::parse_from_attributes(&field.attrs);
+            let field_configuration = form_config.to_field_configuration(Some(field_type));
+            let field_value = value_expr(field_name);
+            let view_summary_call = form_config.view_summary_call(
+                field_type,
+                &field_configuration,
+                quote! { name.push_key(#field_name_str) },
+                field_value,
+            );
+
+            quote! {
+                {
+                    #view_summary_call
+                }
+            }
+        }).collect()
+    }
+
+    /// Generate summaries for the fields that currently hold a valid value
+    fn generate_field_signal_summaries(
+        fields: &syn::punctuated::Punctuated<syn::Field, syn::token::Comma>,
+    ) -> Vec<proc_macro2::TokenStream> {
+        fields.iter().map(|field| {
+            let field_name = field.ident.as_ref().unwrap();
+            let field_name_str = field_name.to_string();
+            let field_type = &field.ty;
+            let form_config = FieldConfigurationParser::parse_from_attributes(&field.attrs);
+            let signal_name = quote::format_ident!("{}_signal", field_name);
+            let field_configuration = form_config.to_field_configuration(Some(field_type));
+            let view_summary_call = form_config.view_summary_call(
+                field_type,
+                &field_configuration,
+                quote! { name.push_key(#field_name_str) },
+                quote! { field_value },
+            );
+
+            // Fields without a valid value show their errors, or that they were not provided
+            quote! {
+                {
+                    let name = name.clone();
+                    move || match #signal_name.get() {
+                        Some(Ok(field_value)) => {
+                            #view_summary_call
+                        }.into_any(),
+                        Some(Err(error)) => view! {
+                            <formidable::components::IncompleteSummaryField label={(#field_configuration).label} error=error />
+                        }.into_any(),
+                        None => view! {
+                            <formidable::components::IncompleteSummaryField label={(#field_configuration).label} />
+                        }.into_any(),
+                    }
+                }
+            }
+        }).collect()
+    }
+
+    /// Generate validation of each field, reading each field from `value_expr`
+    fn generate_field_validations(
+        fields: &syn::punctuated::Punctuated<syn::Field, syn::token::Comma>,
+        value_expr: impl Fn(&syn::Ident) -> proc_macro2::TokenStream,
+    ) -> Vec<proc_macro2::TokenStream> {
+        fields.iter().map(|field| {
+            let field_name = field.ident.as_ref().unwrap();
+            let field_name_str = field_name.to_string();
+            let field_value = value_expr(field_name);
+            let form_config = FieldConfigurationParser::parse_from_attributes(&field.attrs);
+            let validation = match form_config.selection_config() {
+                Some(selection_config) => quote! {
+                    formidable::types::validate_selection(#field_value, name.push_key(#field_name_str), #selection_config)
+                },
+                None => quote! {
+                    formidable::Validate::validate(#field_value, name.push_key(#field_name_str))
+                },
+            };
+
+            let item_count_validation = form_config
+                .item_count_validation(&field_value, &quote! { name.push_key(#field_name_str) })
+                .map(|item_count_validation| quote! {
+                    if let Err(err) = #item_count_validation {
+                        errors.extend(err);
+                    }
+                });
+
+            quote! {
+                if let Err(err) = #validation {
+                    errors.extend(err);
+                }
+                #item_count_validation
+            }
+        }).collect()
+    }
+
+    /// Generate decoding of each field from submitted form fields, binding each field as
+    /// `{field}_decoded`, and the constructor using the decoded fields
+    fn generate_field_decodings(
+        fields: &syn::punctuated::Punctuated<syn::Field, syn::token::Comma>,
+    ) -> (Vec<proc_macro2::TokenStream>, Vec<proc_macro2::TokenStream>) {
+        fields.iter().map(|field| {
+            let field_name = field.ident.as_ref().unwrap();
+            let field_name_str = field_name.to_string();
+            let field_type = &field.ty;
+            let binding = quote::format_ident!("{}_decoded", field_name);
+            let form_config = FieldConfigurationParser::parse_from_attributes(&field.attrs);
+            let decoding = match form_config.selection_config() {
+                Some(selection_config) => quote! {
+                    formidable::types::selection_from_form_fields::<#field_type>(fields, name.push_key(#field_name_str), #selection_config)
+                },
+                None => quote! {
+                    <#field_type as formidable::FromFormFields>::from_form_fields(fields, name.push_key(#field_name_str))
+                },
+            };
+            let decoding = match form_config.item_count_validation(&quote! { value }, &quote! { name.push_key(#field_name_str) }) {
+                Some(item_count_validation) => quote! {
+                    #decoding.and_then(|value| #item_count_validation.map(|_| value))
+                },
+                None => decoding,
+            };
+
+            (
+                quote! {
+                    let #binding = match #decoding {
+                        Ok(value) => Some(value),
+                        Err(err) => {
+                            errors.extend(err);
+                            None
+                        }
+                    };
+                },
+                quote! {
+                    #field_name: #binding.expect("Field should be decoded when there are no errors")
+                },
+            )
+        }).unzip()
+    }
+
     /// Generate unified callback effect for field validation and construction
     fn generate_callback_effect(
         field_signal_names: &[proc_macro2::Ident],
         constructor_type: ConstructorType,
     ) -> proc_macro2::TokenStream {
+        // The cross-field validation of a struct needs the name of the struct in the effect
+        let capture_name = match &constructor_type {
+            ConstructorType::Struct { validate: Some(_), .. } => quote! { let name = name.clone(); },
+            _ => quote! {},
+        };
         let constructor = match constructor_type {
-            ConstructorType::Struct { name, field_constructor } => {
+            ConstructorType::Struct { name, field_constructor, validate } => {
+                // Cross-field validation runs once all fields are valid
+                let validation = validate.map(|validate| quote! {
+                    let validation_result = (#validate)(&merged_struct, name.clone());
+                    validation_errors.set(validation_result.clone().err());
+                    if let Err(err) = validation_result {
+                        parent_callback.run(Err(err));
+                        return;
+                    }
+                });
+
                 quote! {
                     let merged_struct = #name {
                         #(#field_constructor),*
                     };
+                    #validation
                     parent_callback.run(Ok(merged_struct));
                 }
             }
@@ -334,6 +851,7 @@ impl FieldProcessor {
         
         quote! {
             if let Some(parent_callback) = callback {
+                #capture_name
                 leptos::prelude::Effect::new(move || {
                     // Check if all fields have some value (either Ok or Err)
                     let all_fields_have_values = #(#field_signal_names.get().is_some())&&*;
@@ -362,11 +880,21 @@ impl FieldProcessor {
     }
 }
 
+/// The type of the field of a variant with a single unnamed field, which the attributes of the
+/// variant configure
+fn single_field_type(fields: &syn::Fields) -> Option<&syn::Type> {
+    match fields {
+        syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => Some(&fields.unnamed[0].ty),
+        _ => None,
+    }
+}
+
 /// Constructor type for generating different construction logic
 enum ConstructorType<'a> {
     Struct {
         name: &'a syn::Ident,
         field_constructor: &'a [proc_macro2::TokenStream],
+        validate: Option<&'a Expr>,
     },
     EnumVariant {
         enum_name: &'a syn::Ident,
@@ -396,6 +924,28 @@ fn generate_empty_struct_form(name: &syn::Ident) -> TokenStream {
                 // Render nothing for unit structs
                 view! {}.into_any()
             }
+
+            fn view_summary(
+                _field: formidable::FieldConfiguration,
+                _name: formidable::Name,
+                _value: Self,
+            ) -> impl leptos::prelude::IntoView {
+                use leptos::prelude::*;
+
+                view! {}.into_any()
+            }
+        }
+
+        impl formidable::Validate for #name {
+            fn validate(&self, _name: formidable::Name) -> Result<(), formidable::FormError> {
+                Ok(())
+            }
+        }
+
+        impl formidable::FromFormFields for #name {
+            fn from_form_fields(_fields: &formidable::FormFields, _name: formidable::Name) -> Result<Self, formidable::FormError> {
+                Ok(#name)
+            }
         }
     };
 
@@ -405,11 +955,23 @@ fn generate_empty_struct_form(name: &syn::Ident) -> TokenStream {
 fn impl_form_macro(ast: &syn::DeriveInput) -> TokenStream {
     let name = &ast.ident;
 
-    match &ast.data {
+    let mut generated = match &ast.data {
         syn::Data::Struct(data_struct) => impl_form_for_struct(name, data_struct, ast),
         syn::Data::Enum(data_enum) => impl_form_for_enum(name, data_enum, ast),
         _ => panic!("Form can only be derived for structs and enums"),
+    };
+
+    // The name the form is rendered with, e.g. to decode its submission with `Formidable<T>`
+    if let Some(form_name) = FieldConfigurationParser::parse_from_attributes(&ast.attrs).name {
+        let form_name_impl: TokenStream = quote! {
+            impl formidable::FormName for #name {
+                const NAME: &'static str = #form_name;
+            }
+        }.into();
+        generated.extend(form_name_impl);
     }
+
+    generated
 }
 
 fn impl_form_for_enum(name: &syn::Ident, data_enum: &syn::DataEnum, ast: &syn::DeriveInput) -> TokenStream {
@@ -447,7 +1009,7 @@ fn impl_form_for_enum(name: &syn::Ident, data_enum: &syn::DataEnum, ast: &syn::D
     let variant_forms: Vec<_> = variants.iter().map(|variant| {
         let variant_name = &variant.ident;
         let form_config = FieldConfigurationParser::parse_from_attributes(&variant.attrs);
-        let field_configuration = form_config.to_field_configuration();
+        let field_configuration = form_config.to_field_configuration(single_field_type(&variant.fields));
 
         
         match &variant.fields {
@@ -491,7 +1053,7 @@ fn impl_form_for_enum(name: &syn::Ident, data_enum: &syn::DataEnum, ast: &syn::D
                             
                             <#field_type as Form>::view(
                                 #field_configuration,
-                                name, 
+                                name.clone(),
                                 field_value, 
                                 field_callback
                             ).into_any()
@@ -525,9 +1087,11 @@ fn impl_form_for_enum(name: &syn::Ident, data_enum: &syn::DataEnum, ast: &syn::D
                         #callback_effect
 
                         let field_configuration = #field_configuration;
-                        
+                        // The variant is rendered again whenever it is selected
+                        let name = name.clone();
+
                         view! {
-                            <formidable::components::Section name=name heading={field_configuration.label.clone()}>
+                            <formidable::components::Section name=name.clone() heading={field_configuration.label.clone()}>
                                 #(#field_forms)*
                             </formidable::components::Section>
                         }.into_any()
@@ -548,6 +1112,175 @@ fn impl_form_for_enum(name: &syn::Ident, data_enum: &syn::DataEnum, ast: &syn::D
         } }
     }).collect();
     
+    // Generate match arms for the read-only summary, yielding the variant label and its content
+    let variant_summary_arms: Vec<_> = variants.iter().map(|variant| {
+        let variant_name = &variant.ident;
+        let form_config = FieldConfigurationParser::parse_from_attributes(&variant.attrs);
+        let label_string = form_config.label_string();
+        let field_configuration = form_config.to_field_configuration(single_field_type(&variant.fields));
+
+        match &variant.fields {
+            syn::Fields::Unit => {
+                quote! {
+                    #name::#variant_name => (#label_string, ().into_any())
+                }
+            },
+            syn::Fields::Unnamed(fields) => {
+                if fields.unnamed.len() == 1 {
+                    let field_type = &fields.unnamed.first().unwrap().ty;
+
+                    quote! {
+                        #name::#variant_name(inner) => (
+                            #label_string,
+                            <#field_type as Form>::view_summary(#field_configuration, name, inner).into_any()
+                        )
+                    }
+                } else {
+                    panic!("Multiple unnamed fields in enum variants not supported");
+                }
+            },
+            syn::Fields::Named(fields) => {
+                // Bind fields under distinct names so they can't shadow `name` or `field`
+                let field_bindings: Vec<_> = fields.named.iter().map(|field| {
+                    let field_name = field.ident.as_ref().unwrap();
+                    let binding = quote::format_ident!("{}_value", field_name);
+                    quote! { #field_name: #binding }
+                }).collect();
+                let field_summaries = FieldProcessor::generate_field_summaries(&fields.named, |field_name| {
+                    let binding = quote::format_ident!("{}_value", field_name);
+                    quote! { #binding }
+                });
+
+                quote! {
+                    #name::#variant_name { #(#field_bindings),* } => {
+                        let field_configuration = #field_configuration;
+
+                        (
+                            #label_string,
+                            view! {
+                                <formidable::components::SummarySection name=name.clone() heading={field_configuration.label.clone()}>
+                                    #(#field_summaries)*
+                                </formidable::components::SummarySection>
+                            }.into_any()
+                        )
+                    }
+                }
+            }
+        }
+    }).collect();
+
+    let variant_validation_arms: Vec<_> = variants.iter().map(|variant| {
+        let variant_name = &variant.ident;
+        match &variant.fields {
+            syn::Fields::Unit => {
+                quote! { #name::#variant_name => Ok(()) }
+            },
+            syn::Fields::Unnamed(fields) => {
+                if fields.unnamed.len() == 1 {
+                    quote! { #name::#variant_name(inner) => formidable::Validate::validate(inner, name) }
+                } else {
+                    panic!("Multiple unnamed fields in enum variants not supported");
+                }
+            },
+            syn::Fields::Named(fields) => {
+                let field_bindings: Vec<_> = fields.named.iter().map(|field| {
+                    let field_name = field.ident.as_ref().unwrap();
+                    let binding = quote::format_ident!("{}_value", field_name);
+                    quote! { #field_name: #binding }
+                }).collect();
+                let field_validations = FieldProcessor::generate_field_validations(&fields.named, |field_name| {
+                    let binding = quote::format_ident!("{}_value", field_name);
+                    quote! { #binding }
+                });
+
+                quote! {
+                    #name::#variant_name { #(#field_bindings),* } => {
+                        let mut errors: Vec<formidable::FieldError> = Vec::new();
+                        #(#field_validations)*
+
+                        if errors.is_empty() {
+                            Ok(())
+                        } else {
+                            Err(formidable::FormError::from(errors))
+                        }
+                    }
+                }
+            }
+        }
+    }).collect();
+
+    let variant_decoding_arms: Vec<_> = variants.iter().map(|variant| {
+        let variant_name = &variant.ident;
+        let variant_name_str = variant_name.to_string();
+        match &variant.fields {
+            syn::Fields::Unit => {
+                quote! { Some(#variant_name_str) => Ok(#name::#variant_name) }
+            },
+            syn::Fields::Unnamed(fields) => {
+                if fields.unnamed.len() == 1 {
+                    let field_type = &fields.unnamed.first().unwrap().ty;
+                    quote! {
+                        Some(#variant_name_str) => <#field_type as formidable::FromFormFields>::from_form_fields(fields, name).map(#name::#variant_name)
+                    }
+                } else {
+                    panic!("Multiple unnamed fields in enum variants not supported");
+                }
+            },
+            syn::Fields::Named(fields) => {
+                let (field_decodings, field_constructor) = FieldProcessor::generate_field_decodings(&fields.named);
+
+                quote! {
+                    Some(#variant_name_str) => {
+                        let mut errors: Vec<formidable::FieldError> = Vec::new();
+                        #(#field_decodings)*
+
+                        if errors.is_empty() {
+                            Ok(#name::#variant_name { #(#field_constructor),* })
+                        } else {
+                            Err(formidable::FormError::from(errors))
+                        }
+                    }
+                }
+            }
+        }
+    }).collect();
+
+    // Enums with only unit variants can also be chosen from in a multi-select
+    let unit_enum_impl = if !variants.is_empty() && variants.iter().all(|variant| matches!(variant.fields, syn::Fields::Unit)) {
+        let variant_names: Vec<_> = variants.iter().map(|variant| &variant.ident).collect();
+        let variant_name_arms: Vec<_> = variants.iter().map(|variant| {
+            let variant_name = &variant.ident;
+            let variant_name_str = variant_name.to_string();
+            quote! { #name::#variant_name => #variant_name_str }
+        }).collect();
+        let label_arms: Vec<_> = variants.iter().map(|variant| {
+            let variant_name = &variant.ident;
+            let form_config = FieldConfigurationParser::parse_from_attributes(&variant.attrs);
+            let label_string = form_config.label_string();
+            quote! { #name::#variant_name => #label_string }
+        }).collect();
+
+        quote! {
+            impl formidable::types::UnitEnum for #name {
+                const VARIANTS: &'static [Self] = &[#(#name::#variant_names),*];
+
+                fn variant_name(&self) -> &'static str {
+                    match self {
+                        #(#variant_name_arms,)*
+                    }
+                }
+
+                fn label(&self) -> String {
+                    match self {
+                        #(#label_arms,)*
+                    }
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
     // Parse enum attributes to determine variant selection type and class
     let enum_config = FieldConfigurationParser::parse_from_attributes(&ast.attrs);
     let variant_selection_type = enum_config.render_as.as_deref().unwrap_or("radio");
@@ -594,6 +1327,16 @@ fn impl_form_for_enum(name: &syn::Ident, data_enum: &syn::DataEnum, ast: &syn::D
                         #(#discriminant_match_arms,)*
                     }
                 }).unwrap_or_default();
+
+                // Restore the variant selected in a persisted draft
+                let current_discriminant = formidable::draft_value(&name.push_key("variant"))
+                    .and_then(|variant| {
+                        <#discriminant_name as formidable::strum::VariantArray>::VARIANTS
+                            .iter()
+                            .copied()
+                            .find(|discriminant| <&'static str>::from(*discriminant) == variant)
+                    })
+                    .unwrap_or(current_discriminant);
                 
                 let selected_discriminant = RwSignal::new(current_discriminant);
 
@@ -626,7 +1369,55 @@ fn impl_form_for_enum(name: &syn::Ident, data_enum: &syn::DataEnum, ast: &syn::D
                     </div>
                 }.into_any()
             }
+
+            fn view_summary(
+                field: formidable::FieldConfiguration,
+                name: formidable::Name,
+                value: Self,
+            ) -> impl leptos::prelude::IntoView {
+                use leptos::prelude::*;
+
+                let (variant_label, variant_summary) = match value {
+                    #(#variant_summary_arms,)*
+                };
+
+                view! {
+                    <div
+                        class={#enum_class}
+                        style={field.colspan.map(|cols| format!("grid-column: span {};", cols))}
+                    >
+                        <formidable::components::SummaryField label=field.label value=variant_label class=field.class />
+                        <div class="enum-variant">
+                            {variant_summary}
+                        </div>
+                    </div>
+                }.into_any()
+            }
+        }
+
+        impl formidable::Validate for #name {
+            fn validate(&self, name: formidable::Name) -> Result<(), formidable::FormError> {
+                match self {
+                    #(#variant_validation_arms,)*
+                }
+            }
+        }
+
+        impl formidable::FromFormFields for #name {
+            fn from_form_fields(fields: &formidable::FormFields, name: formidable::Name) -> Result<Self, formidable::FormError> {
+                let variant_name = name.push_key("variant");
+                match fields.get(&variant_name) {
+                    #(#variant_decoding_arms,)*
+                    other => Err(formidable::FieldError::new_string(
+                        variant_name,
+                        formidable::FormFieldsError::InvalidVariant,
+                        other.unwrap_or_default().to_owned(),
+                    ).into()),
+                }
+            }
         }
+
+        #unit_enum_impl
     };
 
     generated.into()
@@ -662,15 +1453,95 @@ fn impl_form_for_struct(name: &syn::Ident, data_struct: &syn::DataStruct, ast: &
     let field_signal_names = FieldProcessor::generate_field_signal_names(fields);
     let field_constructor = FieldProcessor::generate_field_constructor(fields);
     let field_forms = FieldProcessor::generate_field_forms(fields);
+    let field_summaries = FieldProcessor::generate_field_summaries(fields, |field_name| quote! { value.#field_name });
+    let field_validations = FieldProcessor::generate_field_validations(fields, |field_name| quote! { &self.#field_name });
+    let (field_decodings, field_decoded_constructor) = FieldProcessor::generate_field_decodings(fields);
+
+    // Errors of the cross-field validation are shown below the fields of the struct
+    let (validation_errors_signal, validation_errors_view, cross_field_validation) = if let Some(validate) = &struct_config.validate {
+        (
+            quote! {
+                let validation_errors: RwSignal<Option<formidable::FormError>> = RwSignal::new(None);
+            },
+            quote! {
+                {move || validation_errors.get().map(|errors| {
+                    errors.into_iter().map(|error| view! {
+                        <p class="message error-message">{error.to_string()}</p>
+                    }).collect_view()
+                })}
+            },
+            quote! { (#validate)(self, name) },
+        )
+    } else {
+        (quote! {}, quote! {}, quote! { Ok(()) })
+    };
+    let decoded_validation = if let Some(validate) = &struct_config.validate {
+        quote! { (#validate)(&value, name)?; }
+    } else {
+        quote! {}
+    };
+
+    // Optionally append a read-only review of the entered values as the last page
+    let review_page = if struct_config.review.unwrap_or(false) {
+        let field_signal_summaries = FieldProcessor::generate_field_signal_summaries(fields);
+        quote! {
+            pages.push(Box::new({
+                let name = name.clone();
+                move || {
+                    // The children of the page take their own copy of the name
+                    let name = name.clone();
+                    view! {
+                        <formidable::components::ReviewPage>
+                            #(#field_signal_summaries)*
+                        </formidable::components::ReviewPage>
+                    }.into_any()
+                }
+            }));
+        }
+    } else {
+        quote! {}
+    };
     
     let callback_effect = FieldProcessor::generate_callback_effect(
         &field_signal_names,
         ConstructorType::Struct {
             name,
             field_constructor: &field_constructor,
+            validate: struct_config.validate.as_ref(),
         },
     );
 
+    // A cell per field when rendered as a row of a table, with the errors of the cross-field
+    // validation in the last cell
+    let columns = fields.iter().map(|field| {
+        let key = field.ident.as_ref().unwrap().to_string();
+        let field_type = &field.ty;
+        let form_config = FieldConfigurationParser::parse_from_attributes(&field.attrs);
+        // Multi-selects and tables are not a single input, even if the type is
+        let pastable = match form_config.render_as.as_deref() {
+            None | Some("slider") if form_config.selection_config().is_none() => {
+                quote! { <#field_type as formidable::Form>::SINGLE_INPUT }
+            }
+            _ => quote! { false },
+        };
+        quote! {
+            formidable::types::TableColumn { key: #key, pastable: #pastable }
+        }
+    });
+    let column_labels = fields.iter().map(|field| {
+        FieldConfigurationParser::parse_from_attributes(&field.attrs).label_string()
+    });
+    let field_cells = field_forms.iter().enumerate().map(|(index, field_form)| {
+        let column = proc_macro2::Literal::usize_unsuffixed(index);
+        let errors = (index + 1 == field_forms.len()).then(|| validation_errors_view.clone());
+        quote! {
+            <td class="table-cell" data-column=#column>
+                #field_form
+                #errors
+            </td>
+        }
+    });
+
     let generated = quote! {
         impl Form for #name {
             fn view(
@@ -683,27 +1554,102 @@ fn impl_form_for_struct(name: &syn::Ident, data_struct: &syn::DataStruct, ast: &
                 
                 // Create signals for each field to track their state
                 #(#field_signals)*
+                #validation_errors_signal
 
                 #callback_effect
 
                 // Choose between Section and PaginatedSection based on render_as attribute
                 if #render_as_type == "paginate" {
-                    let pages: Vec<Box<dyn Fn() -> leptos::prelude::AnyView + Send + Sync>> = vec![
-                        #(Box::new(move || #field_forms.into_any())),*
+                    #[allow(unused_mut)]
+                    let mut pages: Vec<Box<dyn Fn() -> leptos::prelude::AnyView + Send + Sync>> = vec![
+                        #(Box::new({
+                            let name = name.clone();
+                            move || #field_forms.into_any()
+                        })),*
                     ];
+                    #review_page
                     view! {
                         <formidable::components::PaginatedSection name=name heading={field.label} description={field.description} class=#struct_class columns=#struct_columns colspan={field.colspan} pages=pages />
+                        #validation_errors_view
                     }.into_any()
                 } else if #render_as_type == "section" {
                     view! {
-                        <formidable::components::Section name=name heading={field.label} description={field.description} class=#struct_class columns=#struct_columns colspan={field.colspan}>
+                        <formidable::components::Section name=name.clone() heading={field.label} description={field.description} class=#struct_class columns=#struct_columns colspan={field.colspan}>
                             #(#field_forms)*
+                            #validation_errors_view
                         </formidable::components::Section>
                     }.into_any()
                 } else {
                     panic!("Unsupported render_as type for struct: {}. Supported values are 'section' (default) and 'paginate'", #render_as_type);
                 }
             }
+
+            fn view_summary(
+                field: formidable::FieldConfiguration,
+                name: formidable::Name,
+                value: Self,
+            ) -> impl leptos::prelude::IntoView {
+                use leptos::prelude::*;
+
+                view! {
+                    <formidable::components::SummarySection name=name.clone() heading={field.label} class=#struct_class columns=#struct_columns colspan={field.colspan}>
+                        #(#field_summaries)*
+                    </formidable::components::SummarySection>
+                }.into_any()
+            }
+        }
+
+        impl formidable::types::TableRow for #name {
+            const COLUMNS: &'static [formidable::types::TableColumn] = &[#(#columns),*];
+
+            fn column_labels() -> Vec<String> {
+                vec![#(#column_labels),*]
+            }
+
+            fn view_row(
+                name: formidable::Name,
+                value: Option<Self>,
+                callback: Option<leptos::prelude::Callback<Result<Self, formidable::FormError>>>,
+            ) -> impl leptos::prelude::IntoView {
+                use leptos::prelude::*;
+
+                #(#field_signals)*
+                #validation_errors_signal
+
+                #callback_effect
+
+                view! {
+                    #(#field_cells)*
+                }
+            }
+        }
+
+        impl formidable::Validate for #name {
+            fn validate(&self, name: formidable::Name) -> Result<(), formidable::FormError> {
+                let mut errors: Vec<formidable::FieldError> = Vec::new();
+                #(#field_validations)*
+
+                if !errors.is_empty() {
+                    return Err(formidable::FormError::from(errors));
+                }
+
+                #cross_field_validation
+            }
+        }
+
+        impl formidable::FromFormFields for #name {
+            fn from_form_fields(fields: &formidable::FormFields, name: formidable::Name) -> Result<Self, formidable::FormError> {
+                let mut errors: Vec<formidable::FieldError> = Vec::new();
+                #(#field_decodings)*
+
+                if !errors.is_empty() {
+                    return Err(formidable::FormError::from(errors));
+                }
+
+                let value = #name { #(#field_decoded_constructor),* };
+                #decoded_validation
+                Ok(value)
+            }
         }
     };
 
@@ -5,7 +5,9 @@ mod form;
 use leptos::prelude::*;
 use leptos_meta::{provide_meta_context, MetaTags, Stylesheet, Title};
 
-use crate::app::form::ExampleForm;
+use crate::app::form::{ExampleForm, SettingsForm};
+#[cfg(feature = "ssr")]
+pub use crate::app::form::submit_user_form;
 
 include!(concat!(env!("OUT_DIR"), "/i18n/mod.rs"));
 use i18n::*;
@@ -44,6 +46,7 @@ pub fn App() -> impl IntoView {
 
         // content for this welcome page
         <ExampleForm/>
+        <SettingsForm/>
         </I18nContextProvider>
     }
 }
@@ -2,16 +2,18 @@ use leptos::prelude::*;
 
 use formidable::{
     types::{Accept, Color, Date, Email, File, NonEmptyString, Tel},
-    Form, FormConfiguration, FormidableServerAction, SectionHeading,
+    Form, FormConfiguration, FormidableAutosave, FormidableServerAction, SectionHeading,
+    SubmitMode, UndoHistory, ValidationMode,
 };
+use deranged::{RangedU32, RangedU8};
 use leptos::server_fn::codec::Json;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 use url::Url;
 
 #[derive(Form, Clone, Debug, PartialEq, Serialize, Deserialize)]
-#[form(render_as = "paginate")]
-struct FormData {
+#[form(name = "user_form", render_as = "paginate", review = true)]
+pub struct FormData {
     #[form(
         label = personal_information,
         description = "Please provide your personal details."
@@ -19,7 +21,12 @@ struct FormData {
     personal_info: Option<PersonalInfo>,
     #[form(label = "Contact Information")]
     contact_info: ContactInfo,
-    #[form(label = "Order")]
+    #[form(
+        label = "Order",
+        min_items = 1,
+        max_items = 10,
+        item_label = |index, item: &Item| format!("{}. {}", index, item.name)
+    )]
     order: Vec<Item>,
     #[form(label = "Payment Information")]
     payment_info: Payment,
@@ -44,11 +51,15 @@ struct PersonalInfo {
 struct ContactInfo {
     #[form(label = "Address", colspan = 2)]
     address: Address,
-    #[form(label = "Email Address", placeholder = "test@example.com")]
+    #[form(
+        label = "Email Address",
+        placeholder = "test@example.com",
+        validation_mode = "on_blur_then_input"
+    )]
     email: Email,
     #[form(label = "Phone Number")]
     phone: Tel,
-    #[form(label = "Website", colspan = 1)]
+    #[form(label = "Website", colspan = 1, presence = "empty_is_none")]
     website: Option<Url>,
 }
 
@@ -75,6 +86,8 @@ struct Item {
     name: String,
     #[form(label = "Item ID")]
     id: u32,
+    #[form(label = "Quantity")]
+    quantity: RangedU32<1, 99>,
 }
 
 #[derive(Form, Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -120,6 +133,46 @@ enum PaymentMethod {
     Cash,
 }
 
+#[derive(Form, Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct Settings {
+    #[form(label = "Display Name")]
+    display_name: NonEmptyString,
+    #[form(label = "Receive Newsletter")]
+    newsletter: bool,
+    #[form(label = "Country")]
+    country: Country,
+    #[form(label = "Volume", render_as = "slider")]
+    volume: RangedU8<0, 100>,
+    #[form(label = "Interests", render_as = "checkboxes", max_selected = 3)]
+    interests: Vec<Interest>,
+    #[form(label = "Emergency Contacts", render_as = "table", max_items = 5)]
+    emergency_contacts: Vec<EmergencyContact>,
+}
+
+#[derive(Form, Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct EmergencyContact {
+    #[form(label = "Name")]
+    name: NonEmptyString,
+    #[form(label = "Relationship")]
+    relationship: String,
+    #[form(label = "Phone Number")]
+    phone: Tel,
+}
+
+#[derive(Form, Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+enum Interest {
+    #[form(label = "Sports")]
+    Sports,
+    #[form(label = "Music")]
+    Music,
+    #[form(label = "Travel")]
+    Travel,
+    #[form(label = "Cooking")]
+    Cooking,
+    #[form(label = "Technology")]
+    Technology,
+}
+
 #[component]
 pub fn ExampleForm() -> impl IntoView {
     view! {
@@ -129,11 +182,38 @@ pub fn ExampleForm() -> impl IntoView {
             name="user_form"
             form_configuration=FormConfiguration {
                 section_label: SectionHeading::LeveledHeading(formidable::HeadingLevel::H2),
+                submit_mode: SubmitMode::RevealErrors,
+                validation_mode: ValidationMode::OnBlur,
+                error_summary: true,
             }
+            action="/user-form"
+            enctype="multipart/form-data"
+            persist="example_form"
+            history=UndoHistory::new().keyboard_shortcuts()
+        />
+    }
+}
+
+#[component]
+pub fn SettingsForm() -> impl IntoView {
+    view! {
+        <FormidableAutosave<SaveSettings, Settings>
+            label="Settings"
+            description="Changes are saved automatically."
+            name="settings"
         />
     }
 }
 
+#[server(
+  input = Json,
+  output = Json
+)]
+async fn save_settings(settings: Settings) -> Result<(), ServerFnError> {
+    leptos::logging::log!("Saved settings: {:?}", settings);
+    Ok(())
+}
+
 #[server(
   input = Json,
   output = Json
@@ -142,3 +222,13 @@ async fn handle_submit(user_form: FormData) -> Result<(), ServerFnError> {
     leptos::logging::log!("Received form submission: {:?}", user_form);
     Ok(())
 }
+
+// Forms submitted before hydration are posted to the `action` of the form instead, as
+// `multipart/form-data` because of the file upload
+#[cfg(feature = "ssr")]
+pub async fn submit_user_form(
+    formidable::Formidable(user_form): formidable::Formidable<FormData>,
+) -> axum::response::Redirect {
+    leptos::logging::log!("Received form submission without JavaScript: {:?}", user_form);
+    axum::response::Redirect::to("/")
+}
@@ -1,23 +1,33 @@
 mod section_heading_view;
 pub use section_heading_view::*;
+mod async_validation;
 mod checkbox;
 mod description;
 mod error_message;
+mod error_summary;
 #[cfg(feature = "file")]
 mod file_input;
 mod input;
+mod label;
+mod multi_select;
 pub mod paginated_section;
 mod radio;
 mod section;
 mod select;
+mod summary;
 
+pub use async_validation::*;
 pub use checkbox::*;
 pub use description::*;
 pub use error_message::*;
+pub use error_summary::*;
 #[cfg(feature = "file")]
 pub use file_input::*;
 pub use input::*;
+pub use label::HiddenLabels;
+pub use multi_select::*;
 pub use paginated_section::*;
 pub use radio::*;
 pub use section::*;
 pub use select::*;
+pub use summary::*;
@@ -0,0 +1,212 @@
+use std::{fmt::Display, future::Future, pin::Pin, sync::Arc, time::Duration};
+
+use futures::future::{abortable, AbortHandle};
+use leptos::{prelude::*, task::spawn_local};
+
+use crate::{
+    t, use_form_context, FieldContext, FieldError, FormContext, FormError, FormMessage, Name,
+};
+
+type ValidateFn<T> =
+    Arc<dyn Fn(T) -> Pin<Box<dyn Future<Output = Result<(), String>>>> + Send + Sync>;
+
+/// Validates the value of a field with an async function, such as a server function,
+/// after the value passed its synchronous validation.
+///
+/// Checks are debounced, and a check is aborted once it is superseded by a newer value. While
+/// a check is outstanding, the form can't be submitted. Errors of failed checks are shown
+/// under the field by [`ValidationPending`].
+pub struct AsyncValidator<T: Send + Sync + 'static> {
+    name: StoredValue<Name>,
+    validate: StoredValue<ValidateFn<T>>,
+    debounce: Duration,
+    pending: RwSignal<bool>,
+    error: RwSignal<Option<String>>,
+    timeout: StoredValue<Option<TimeoutHandle>>,
+    check: StoredValue<Option<AbortHandle>>,
+    form_context: Option<FormContext>,
+}
+
+impl<T: Send + Sync + 'static> Clone for AsyncValidator<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Send + Sync + 'static> Copy for AsyncValidator<T> {}
+
+impl<T> AsyncValidator<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    pub const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(300);
+
+    pub fn new<F, Fut, E>(name: Name, debounce: Duration, validate: F) -> Self
+    where
+        F: Fn(T) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), E>> + 'static,
+        E: Display + 'static,
+    {
+        let validate: ValidateFn<T> = Arc::new(move |value| {
+            let future = validate(value);
+            Box::pin(async move { future.await.map_err(|err| err.to_string()) })
+        });
+
+        let validator = AsyncValidator {
+            name: StoredValue::new(name),
+            validate: StoredValue::new(validate),
+            debounce,
+            pending: RwSignal::new(false),
+            error: RwSignal::new(None),
+            timeout: StoredValue::new(None),
+            check: StoredValue::new(None),
+            form_context: use_form_context(),
+        };
+        // The field may not have a `FieldContext` of its own, e.g. if it is a struct
+        if let Some(form_context) = validator.form_context {
+            form_context.register_pending(validator.pending);
+        }
+        // Checks of removed fields are not needed anymore
+        on_cleanup(move || validator.cancel());
+        validator
+    }
+
+    /// Whether a check is outstanding.
+    pub fn pending(&self) -> Signal<bool> {
+        self.pending.into()
+    }
+
+    /// The error of the last check, if it failed.
+    pub fn error(&self) -> Signal<Option<String>> {
+        self.error.into()
+    }
+
+    /// Checks the result of the synchronous validation and reports the final result to `callback`.
+    ///
+    /// Valid values are reported right away, and the result of the check once it failed.
+    pub fn validate(&self, result: Result<T, FormError>, callback: Callback<Result<T, FormError>>) {
+        // Supersede any outstanding check
+        self.cancel();
+        self.set_error(None);
+
+        let value = match result {
+            Ok(value) => value,
+            Err(err) => {
+                self.set_pending(false);
+                callback.run(Err(err));
+                return;
+            }
+        };
+
+        self.set_pending(true);
+        callback.run(Ok(value.clone()));
+
+        let validator = *self;
+        let timeout = set_timeout_with_handle(
+            move || {
+                let Some(validate) = validator.validate.try_get_value() else {
+                    return;
+                };
+                let (check, abort_handle) = abortable(validate(value));
+                validator.check.set_value(Some(abort_handle));
+
+                spawn_local(async move {
+                    // Aborted checks were superseded or their field was removed
+                    let Ok(result) = check.await else {
+                        return;
+                    };
+                    validator.check.try_set_value(None);
+                    validator.set_pending(false);
+                    if let Err(message) = result {
+                        validator.set_error(Some(message.clone()));
+                        let Some(name) = validator.name.try_get_value() else {
+                            return;
+                        };
+                        callback.run(Err(FieldError::new(name, message).into()));
+                    }
+                });
+            },
+            self.debounce,
+        )
+        .ok();
+        self.timeout.set_value(timeout);
+    }
+
+    /// Aborts the outstanding check, if any.
+    fn cancel(&self) {
+        if let Some(timeout) = self.timeout.try_update_value(Option::take).flatten() {
+            timeout.clear();
+        }
+        if let Some(check) = self.check.try_update_value(Option::take).flatten() {
+            check.abort();
+        }
+    }
+
+    /// The field of the validated value, if it is a single input, which shows the check.
+    fn field(&self) -> Option<FieldContext> {
+        let name = self.name.try_get_value()?;
+        self.form_context
+            .and_then(|form_context| untrack(|| form_context.field(&name)))
+    }
+
+    fn set_pending(&self, pending: bool) {
+        self.pending.try_set(pending);
+        if let Some(field) = self.field() {
+            field.validation_pending().try_set(pending);
+        }
+    }
+
+    fn set_error(&self, error: Option<String>) {
+        self.error.try_set(error.clone());
+        if let Some(field) = self.field() {
+            field.async_error().try_set(error);
+        }
+    }
+}
+
+/// Shows that a check of an [`AsyncValidator`] is outstanding, or the error of the last check.
+#[component]
+pub fn ValidationPending(
+    #[prop(into)] pending: Signal<bool>,
+    #[prop(into, optional)] error: Option<Signal<Option<String>>>,
+) -> impl IntoView {
+    move || {
+        if pending.get() {
+            Some(view! { <p class="message info-message validation-pending-message">{t(FormMessage::ValidationPendingMessage)}</p> }.into_any())
+        } else {
+            error.and_then(|error| error.get()).map(|error| {
+                view! { <p class="message error-message async-error-message">{error}</p> }
+                    .into_any()
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pending_check_of_non_single_input_field_blocks_submit() {
+        let owner = Owner::new();
+        owner.with(|| {
+            let form_context = FormContext::new(Name::from("form"));
+            provide_context(form_context);
+
+            // Structs have no `FieldContext` under their own name
+            let validator = AsyncValidator::<Vec<String>>::new(
+                Name::from("form[address]"),
+                Duration::ZERO,
+                |_| async { Ok::<_, String>(()) },
+            );
+            assert!(form_context.field(&Name::from("form[address]")).is_none());
+            assert!(!form_context.is_pending());
+
+            validator.set_pending(true);
+            assert!(form_context.is_pending());
+
+            validator.set_pending(false);
+            assert!(!form_context.is_pending());
+        });
+    }
+}
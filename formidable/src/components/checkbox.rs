@@ -1,8 +1,8 @@
 use leptos::prelude::*;
 
 use crate::{
-    components::{Description, ErrorMessage},
-    FieldError, Name,
+    components::{label::label_style, shown_result, Description, ErrorMessage},
+    draft_value, persist_draft_value, FieldContext, FieldError, Name, ValidationMode,
 };
 use std::fmt::Display;
 
@@ -17,21 +17,39 @@ pub fn Checkbox<T>(
     #[prop(into)] callback: Option<Callback<Result<T, FieldError>>>,
     #[prop(into, default = None)] class: Option<String>,
     #[prop(into, default = None)] colspan: Option<u32>,
+    #[prop(into, default = None)] validation_mode: Option<ValidationMode>,
 ) -> impl IntoView
 where
     T: Clone + Into<bool> + TryFrom<bool> + Send + Sync + 'static,
     T::Error: Clone + Display + Send + Sync + 'static,
 {
     let node_ref = NodeRef::new();
-    let touched = RwSignal::<bool>::new(false);
-    let raw_value = RwSignal::new(value.map(Into::into).unwrap_or_default());
+    let validation_mode = ValidationMode::or_configured(validation_mode);
+    let field_context = FieldContext::register(name.clone());
+    field_context.label().set(Some(label.get().to_string()));
+    let touched = field_context.touched();
+    let disabled = field_context.disabled();
+    let dirty = field_context.dirty();
+    let server_error = field_context.server_error();
+    let raw_value = RwSignal::new(
+        draft_value(&name)
+            .map(|draft| draft == "true")
+            .unwrap_or_else(|| value.map(Into::into).unwrap_or_default()),
+    );
+    persist_draft_value(name.clone(), move || raw_value.get().to_string());
     let value = Signal::derive(move || T::try_from(raw_value.get()));
 
-    if let Some(callback) = callback {
-        Effect::new(move |_| {
-            callback.run(value.get().map_err(|err| FieldError::new(name, err)));
-        });
-    }
+    Effect::new(move |_| {
+        let result = value
+            .get()
+            .map_err(|err| FieldError::new(field_context.name(), err));
+        field_context.error().set(result.as_ref().err().cloned());
+        if let Some(callback) = callback {
+            callback.run(result);
+        }
+    });
+
+    let shown_value = shown_result(validation_mode, touched, value);
 
     node_ref.on_load(move |elem: leptos::web_sys::HtmlInputElement| {
         let input_value = elem.checked();
@@ -42,7 +60,7 @@ where
 
     view! {
         <div
-            class:error={move || touched.get() && value.get().is_err()}
+            class:error={move || (touched.get() && shown_value.get().is_err()) || server_error.get().is_some()}
             class={format!("field checkbox-field{}", class.as_ref().map(|c| format!(" {}", c)).unwrap_or_default())}
             style={colspan.map(|cols| format!("grid-column: span {};", cols))}
         >
@@ -54,18 +72,25 @@ where
                     name=name.to_string()
                     id=name.to_string()
                     checked=move || raw_value.get()
-                    on:focus=move |_| {
-                        touched.set(true);
+                    disabled=move || disabled.get()
+                    on:blur=move |_| {
+                        if validation_mode.validates_on_blur() {
+                            touched.set(true);
+                        }
                     }
                     on:change=move |ev| {
-                        touched.set(true);
+                        if validation_mode.validates_on_input() {
+                            touched.set(true);
+                        }
+                        dirty.set(true);
+                        server_error.set(None);
                         let checked = event_target_checked(&ev);
                         raw_value.set(checked);
                     }
                 />
-                {label.get()}
+                <span style=label_style()>{label.get()}</span>
             </label>
-            <ErrorMessage touched={touched} value={value} />
+            <ErrorMessage touched={touched} value={shown_value} server_error={server_error} />
             <Description description={description} />
         </div>
     }
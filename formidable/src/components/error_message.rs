@@ -2,10 +2,13 @@ use std::fmt::Display;
 
 use leptos::prelude::*;
 
+use crate::ValidationMode;
+
 #[component]
 pub fn ErrorMessage<T, E>(
     #[prop(into)] touched: Signal<bool>,
     #[prop(into)] value: Signal<Result<T, E>>,
+    #[prop(into, optional)] server_error: Option<Signal<Option<String>>>,
 ) -> impl IntoView
 where
     T: Clone + Send + Sync + 'static,
@@ -17,5 +20,34 @@ where
                 view! { <p class="message error-message">{format!("{}", e)}</p> }
             }))
         }}
+        { move || {
+            server_error.and_then(|server_error| server_error.get()).map(|e| {
+                view! { <p class="message error-message server-error-message">{e}</p> }
+            })
+        }}
     }
 }
+
+/// The validation result shown to the user. With `ValidationMode::OnBlur`, this is the result
+/// at the time the field was last touched instead of the current one.
+pub(crate) fn shown_result<T, E>(
+    validation_mode: ValidationMode,
+    touched: RwSignal<bool>,
+    value: Signal<Result<T, E>>,
+) -> Signal<Result<T, E>>
+where
+    T: Clone + Send + Sync + 'static,
+    E: Clone + Send + Sync + 'static,
+{
+    if validation_mode != ValidationMode::OnBlur {
+        return value;
+    }
+
+    let shown = RwSignal::new(value.get_untracked());
+    Effect::new(move |_| {
+        touched.track();
+        shown.set(value.get_untracked());
+    });
+
+    shown.into()
+}
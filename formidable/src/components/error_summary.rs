@@ -0,0 +1,57 @@
+use leptos::prelude::*;
+
+use crate::{t, use_form_context, FormMessage};
+
+/// Lists the errors of all fields after the user tried to submit an invalid form, with
+/// links that focus the corresponding field, followed by errors reported by the server that
+/// don't belong to any field. Must be rendered inside a `Formidable*` form.
+#[component]
+pub fn ErrorSummary(#[prop(into, optional)] heading: Option<TextProp>) -> impl IntoView {
+    let form_context = use_form_context().expect("ErrorSummary must be rendered inside a form");
+
+    move || {
+        if !form_context.submitted().get() {
+            return None;
+        }
+
+        let errors = form_context.field_error_messages();
+        let server_errors = form_context.server_errors().get();
+        if errors.is_empty() && server_errors.is_empty() {
+            return None;
+        }
+
+        let heading = heading
+            .as_ref()
+            .map(|heading| heading.get().to_string())
+            .unwrap_or_else(|| t(FormMessage::ErrorSummaryHeading));
+
+        Some(view! {
+            <div class="error-summary" role="alert">
+                <p class="error-summary-heading">{heading}</p>
+                <ul class="error-summary-list">
+                    { errors.into_iter().map(|(name, message)| {
+                        let label = form_context
+                            .field(&name)
+                            .and_then(|field| field.label().get())
+                            .unwrap_or_else(|| name.to_string());
+
+                        view! {
+                            <li>
+                                <a
+                                    href=format!("#{}", name)
+                                    on:click=move |ev| {
+                                        ev.prevent_default();
+                                        form_context.focus_field(&name);
+                                    }
+                                >
+                                    {format!("{}: {}", label, message)}
+                                </a>
+                            </li>
+                        }
+                    }).collect_view() }
+                    { server_errors.into_iter().map(|error| view! { <li>{error}</li> }).collect_view() }
+                </ul>
+            </div>
+        })
+    }
+}
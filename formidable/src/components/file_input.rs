@@ -1,9 +1,9 @@
 use leptos::prelude::*;
 use leptos::task::spawn_local;
 
-use crate::components::{Description, ErrorMessage};
+use crate::components::{label::label_style, shown_result, Description, ErrorMessage};
 use crate::types::FileError;
-use crate::{types::File, FieldError, Name};
+use crate::{types::File, FieldContext, FieldError, Name, ValidationMode};
 use leptos::wasm_bindgen::JsCast;
 use leptos::web_sys;
 
@@ -23,17 +23,29 @@ pub fn FileInput(
     #[prop(into)] name: Name,
     #[prop(into)] value: Option<File>,
     #[prop(into)] callback: Option<Callback<Result<File, FieldError>>>,
+    #[prop(into, default = None)] validation_mode: Option<ValidationMode>,
 ) -> impl IntoView {
     let node_ref = NodeRef::new();
-    let touched = RwSignal::new(false);
+    let validation_mode = ValidationMode::or_configured(validation_mode);
+    let field_context = FieldContext::register(name.clone());
+    field_context.label().set(Some(label.get().to_string()));
+    let touched = field_context.touched();
+    let disabled = field_context.disabled();
+    let dirty = field_context.dirty();
+    let server_error = field_context.server_error();
     let current_file = RwSignal::new(value.ok_or(FileError::NoFileSelected));
 
-    if let Some(callback) = callback {
-        Effect::new(move |_| {
-            let result = current_file.get().map_err(|err| FieldError::new(name, err));
+    Effect::new(move |_| {
+        let result = current_file
+            .get()
+            .map_err(|err| FieldError::new(field_context.name(), err));
+        field_context.error().set(result.as_ref().err().cloned());
+        if let Some(callback) = callback {
             callback.run(result);
-        });
-    }
+        }
+    });
+
+    let shown_file = shown_result(validation_mode, touched, current_file.into());
 
     let update_current_file = move |input: web_sys::HtmlInputElement| {
         if let Some(files) = input.files() {
@@ -63,7 +75,11 @@ pub fn FileInput(
     };
 
     let handle_file_change = move |ev: web_sys::Event| {
-        touched.set(true);
+        if validation_mode.validates_on_input() {
+            touched.set(true);
+        }
+        dirty.set(true);
+        server_error.set(None);
 
         if let Some(input) = ev
             .target()
@@ -78,16 +94,20 @@ pub fn FileInput(
     });
 
     view! {
-        <div class:error={move || touched.get() && current_file.get().is_err()} class="field file-input-field">
-            <label for=name.to_string()>{label.get()}
+        <div class:error={move || (touched.get() && shown_file.get().is_err()) || server_error.get().is_some()} class="field file-input-field">
+            <label for=name.to_string()>
+                <span style=label_style()>{label.get()}</span>
                 <span class="custom custom-file-input"></span>
                 <input
                     node_ref=node_ref
                     type="file"
                     name=name.to_string()
                     id=name.to_string()
-                    on:focus=move |_| {
-                        touched.set(true);
+                    disabled=move || disabled.get()
+                    on:blur=move |_| {
+                        if validation_mode.validates_on_blur() {
+                            touched.set(true);
+                        }
                     }
                     on:change=handle_file_change
                 />
@@ -97,7 +117,7 @@ pub fn FileInput(
                     { format!("{}", file) }
                 </span>
             })}
-            <ErrorMessage touched={touched} value={current_file} />
+            <ErrorMessage touched={touched} value={shown_file} server_error={server_error} />
             <Description description={description} />
         </div>
     }
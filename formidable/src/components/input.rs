@@ -1,7 +1,10 @@
 use leptos::prelude::*;
 
-use crate::components::{Description, ErrorMessage};
-use crate::{FieldError, Name};
+use crate::components::{label::label_style, shown_result, Description, ErrorMessage};
+use crate::{
+    draft::keep_draft_value_private, draft_value, persist_draft_value, FieldContext, FieldError,
+    Name, ValidationMode,
+};
 use std::fmt::Display;
 use std::str::FromStr;
 
@@ -20,27 +23,65 @@ pub fn Input<T>(
     #[prop(into, default = None)] required: Option<bool>,
     #[prop(into, default = None)] minlength: Option<usize>,
     #[prop(into, default = None)] maxlength: Option<usize>,
+    #[prop(into, default = None)] pattern: Option<&'static str>,
     #[prop(into, default = Vec::default())] datalist: Vec<T>,
     #[prop(into, default = None)] class: Option<String>,
     #[prop(into, default = None)] colspan: Option<u32>,
+    #[prop(into, default = None)] validation_mode: Option<ValidationMode>,
 ) -> impl IntoView
 where
     T: Clone + Display + FromStr + Send + Sync + 'static,
     T::Err: Clone + Display + Send + Sync + 'static,
 {
     let node_ref = NodeRef::new();
-    let touched = RwSignal::new(false);
-    let raw_value = RwSignal::new(value.map(|v| v.to_string()).unwrap_or_default());
+    let validation_mode = ValidationMode::or_configured(validation_mode);
+    let field_context = FieldContext::register(name.clone());
+    field_context.label().set(Some(label.get().to_string()));
+    let touched = field_context.touched();
+    let disabled = field_context.disabled();
+    let dirty = field_context.dirty();
+    let server_error = field_context.server_error();
+    let raw_value = RwSignal::new(
+        draft_value(&name).unwrap_or_else(|| value.map(|v| v.to_string()).unwrap_or_default()),
+    );
+    persist_draft_value(name.clone(), move || raw_value.get());
+    // Passwords are never written to the browser storage in plain text
+    if input_type == InputType::Password {
+        keep_draft_value_private(name.clone());
+    }
     let value = Signal::derive(move || raw_value.get().parse::<T>());
 
-    if let Some(callback) = callback {
-        Effect::new(move |_| {
-            let result = value
-                .get()
-                .map_err(|err| FieldError::new_string(name, err, raw_value.get_untracked()));
-            callback.run(result);
+    // Empty input is valid if the field is the value of an optional field
+    let optional_input = use_context::<OptionalInput>()
+        .filter(|optional| optional.name == name)
+        .map(|optional| optional.empty);
+    field_context.optional().set(optional_input.is_some());
+    let is_empty = move || optional_input.is_some() && raw_value.with(String::is_empty);
+    let checked = Signal::derive(move || {
+        if is_empty() {
+            Ok(())
+        } else {
+            value.get().map(|_| ())
+        }
+    });
+
+    Effect::new(move |_| {
+        let result = value.get().map_err(|err| {
+            FieldError::new_string(field_context.name(), err, raw_value.get_untracked())
         });
-    }
+        let empty = untrack(is_empty);
+        if let Some(optional_empty) = optional_input {
+            optional_empty.set(empty);
+        }
+        field_context
+            .error()
+            .set(result.as_ref().err().filter(|_| !empty).cloned());
+        if let Some(callback) = callback {
+            callback.run(result);
+        }
+    });
+
+    let shown_value = shown_result(validation_mode, touched, checked);
 
     node_ref.on_load(move |elem: leptos::web_sys::HtmlInputElement| {
         let input_value = elem.value();
@@ -51,11 +92,11 @@ where
 
     view! {
         <div
-            class:error={move || touched.get() && value.get().is_err()}
+            class:error={move || (touched.get() && shown_value.get().is_err()) || server_error.get().is_some()}
             class={format!("field input-field{}", class.as_ref().map(|c| format!(" {}", c)).unwrap_or_default())}
             style={colspan.map(|cols| format!("grid-column: span {};", cols))}
         >
-            <label for=name.to_string()>{label.get()}</label>
+            <label for=name.to_string() style=label_style()>{label.get()}</label>
             <input
                 node_ref=node_ref
                 type={match input_type {
@@ -74,17 +115,24 @@ where
                 name=name.to_string()
                 id=name.to_string()
                 value={move || raw_value.get()}
+                disabled={move || disabled.get()}
                 colorpick-eyedropper-active={
                     match input_type {
                         InputType::Color => Some("true"),
                         _ => None,
                     }
                 }
-                on:focus=move |_| {
-                    touched.set(true);
+                on:blur=move |_| {
+                    if validation_mode.validates_on_blur() {
+                        touched.set(true);
+                    }
                 }
                 on:input=move |ev| {
-                    touched.set(true);
+                    if validation_mode.validates_on_input() {
+                        touched.set(true);
+                    }
+                    dirty.set(true);
+                    server_error.set(None);
                     let input = event_target_value(&ev);
                     raw_value.set(input.clone());
                 }
@@ -92,9 +140,10 @@ where
                 max={max.as_ref().map(|v| v.to_string())}
                 step={step.as_ref().map(|v| v.to_string())}
                 placeholder={placeholder.as_ref().map(|v| v.to_string())}
-                required={required}
+                required={required.filter(|_| optional_input.is_none())}
                 minlength={minlength.as_ref().copied()}
                 maxlength={maxlength.as_ref().copied()}
+                pattern={pattern}
                 list={if !datalist.is_empty() {
                     Some(format!("{}-datalist", name))
                 } else {
@@ -117,12 +166,20 @@ where
                     ().into_any()
                 }
             }
-            <ErrorMessage touched={touched} value={value} />
+            <ErrorMessage touched={touched} value={shown_value} server_error={server_error} />
             <Description description={description} />
         </div>
     }
 }
 
+/// Provided by an `Option` field with `Presence::EmptyIsNone` to the input of its value, which
+/// then accepts empty input and reports whether it is empty.
+#[derive(Clone)]
+pub(crate) struct OptionalInput {
+    pub(crate) name: Name,
+    pub(crate) empty: RwSignal<bool>,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum InputType {
     #[default]
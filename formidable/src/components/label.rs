@@ -0,0 +1,13 @@
+use leptos::prelude::*;
+
+/// Hides the labels of the fields rendered inside it, while keeping them for screen readers,
+/// e.g. in the cells of a table whose columns are labelled by its header row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HiddenLabels;
+
+/// The style of the label of a field, which hides it visually inside [`HiddenLabels`].
+pub(crate) fn label_style() -> Option<&'static str> {
+    use_context::<HiddenLabels>().map(|_| {
+        "position: absolute; width: 1px; height: 1px; overflow: hidden; clip-path: inset(50%); white-space: nowrap;"
+    })
+}
@@ -0,0 +1,202 @@
+use leptos::{prelude::*, wasm_bindgen::JsCast};
+
+use crate::{
+    components::{label::label_style, shown_result, Description, ErrorMessage},
+    draft_value, persist_draft_value,
+    types::{is_selected, SelectionConfig, SelectionMode, UnitEnum},
+    FieldContext, FieldError, Name, ValidationMode,
+};
+
+#[component]
+pub fn MultiSelect<T>(
+    #[prop(into)] label: TextProp,
+    #[prop(into, default = None)] description: Option<TextProp>,
+    #[prop(into)] name: Name,
+    #[prop(into)] value: Option<Vec<T>>,
+    #[prop(into)] callback: Option<Callback<Result<Vec<T>, FieldError>>>,
+    #[prop(into, default = SelectionMode::Checkboxes)] render_as: SelectionMode,
+    #[prop(into, default = None)] min: Option<usize>,
+    #[prop(into, default = None)] max: Option<usize>,
+    #[prop(into, default = None)] class: Option<String>,
+    #[prop(into, default = None)] colspan: Option<u32>,
+    #[prop(into, default = None)] validation_mode: Option<ValidationMode>,
+) -> impl IntoView
+where
+    T: UnitEnum,
+{
+    let validation_mode = ValidationMode::or_configured(validation_mode);
+    let config = SelectionConfig {
+        render_as,
+        min,
+        max,
+    };
+    let field_context = FieldContext::register(name.clone());
+    field_context.label().set(Some(label.get().to_string()));
+    let touched = field_context.touched();
+    let disabled = field_context.disabled();
+    let dirty = field_context.dirty();
+    let server_error = field_context.server_error();
+
+    // The selected variants, kept in the order of their declaration
+    let selected = RwSignal::new(match draft_value(&name) {
+        Some(draft) => {
+            let draft = draft.split(',').collect::<Vec<_>>();
+            T::VARIANTS
+                .iter()
+                .filter(|variant| draft.contains(&variant.variant_name()))
+                .cloned()
+                .collect::<Vec<_>>()
+        }
+        None => {
+            let value = value.unwrap_or_default();
+            T::VARIANTS
+                .iter()
+                .filter(|variant| is_selected(&value, variant))
+                .cloned()
+                .collect::<Vec<_>>()
+        }
+    });
+    persist_draft_value(name.clone(), move || {
+        selected
+            .get()
+            .iter()
+            .map(UnitEnum::variant_name)
+            .collect::<Vec<_>>()
+            .join(",")
+    });
+    let value = Signal::derive(move || {
+        let selected = selected.get();
+        config
+            .check(selected.len())
+            .map(|_| selected)
+            .map_err(|err| FieldError::new(field_context.name(), err))
+    });
+
+    Effect::new(move |_| {
+        let result = value.get();
+        field_context.error().set(result.as_ref().err().cloned());
+        if let Some(callback) = callback {
+            callback.run(result);
+        }
+    });
+
+    let shown_value = shown_result(validation_mode, touched, value);
+
+    let on_blur = move || {
+        if validation_mode.validates_on_blur() {
+            touched.set(true);
+        }
+    };
+    let on_change = move |items: Vec<T>| {
+        if validation_mode.validates_on_input() {
+            touched.set(true);
+        }
+        dirty.set(true);
+        server_error.set(None);
+        selected.set(items);
+    };
+
+    let options = match render_as {
+        SelectionMode::Checkboxes => view! {
+            <fieldset>
+                <legend style=label_style()>{label.get()}</legend>
+                { T::VARIANTS.iter().map(move |option| {
+                    let option_value = option.variant_name();
+                    let id = format!("{}-{}", name, option_value);
+                    let label_for = id.clone();
+                    let is_checked = {
+                        let option = option.clone();
+                        move || selected.with(|selected| is_selected(selected, &option))
+                    };
+                    let option = option.clone();
+                    view! {
+                        <div class="field checkbox-field">
+                            <label for=label_for>
+                                <span class="custom custom-checkbox"></span>
+                                <input
+                                    type="checkbox"
+                                    name=name.to_string()
+                                    id=id
+                                    value=option_value
+                                    checked=is_checked
+                                    disabled=move || disabled.get()
+                                    on:blur=move |_| on_blur()
+                                    on:change=move |ev| {
+                                        let checked = event_target_checked(&ev);
+                                        let items = T::VARIANTS
+                                            .iter()
+                                            .filter(|variant| {
+                                                if variant.variant_name() == option_value {
+                                                    checked
+                                                } else {
+                                                    selected.with_untracked(|selected| is_selected(selected, variant))
+                                                }
+                                            })
+                                            .cloned()
+                                            .collect();
+                                        on_change(items);
+                                    }
+                                />
+                                {option.label()}
+                            </label>
+                        </div>
+                    }
+                }).collect::<Vec<_>>() }
+            </fieldset>
+        }
+        .into_any(),
+        SelectionMode::SelectMultiple => view! {
+            <label for=name.to_string() style=label_style()>{label.get()}</label>
+            <select
+                multiple
+                name=name.to_string()
+                id=name.to_string()
+                disabled=move || disabled.get()
+                on:blur=move |_| on_blur()
+                on:change=move |ev| {
+                    let select = event_target::<leptos::web_sys::HtmlSelectElement>(&ev);
+                    let options = select.selected_options();
+                    let values = (0..options.length())
+                        .filter_map(|index| options.item(index))
+                        .filter_map(|option| option.dyn_into::<leptos::web_sys::HtmlOptionElement>().ok())
+                        .map(|option| option.value())
+                        .collect::<Vec<_>>();
+                    let items = T::VARIANTS
+                        .iter()
+                        .filter(|variant| values.iter().any(|value| value == variant.variant_name()))
+                        .cloned()
+                        .collect();
+                    on_change(items);
+                }
+            >
+                { T::VARIANTS.iter().map(move |option| {
+                    let is_selected = {
+                        let option = option.clone();
+                        move || selected.with(|selected| is_selected(selected, &option))
+                    };
+                    view! {
+                        <option
+                            value=option.variant_name()
+                            selected=is_selected
+                        >
+                            {option.label()}
+                        </option>
+                    }
+                }).collect::<Vec<_>>() }
+            </select>
+        }
+        .into_any(),
+    };
+
+    view! {
+        <div
+            class:error={move || (touched.get() && shown_value.get().is_err()) || server_error.get().is_some()}
+            class={format!("field multi-select-field{}", class.as_ref().map(|c| format!(" {}", c)).unwrap_or_default())}
+            style={colspan.map(|cols| format!("grid-column: span {};", cols))}
+        >
+            {options}
+            <ErrorMessage touched={touched} value={shown_value} server_error={server_error} />
+            <Description description={description} />
+        </div>
+    }
+}
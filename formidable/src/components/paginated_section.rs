@@ -1,11 +1,15 @@
-use leptos::prelude::*;
+use leptos::{context::Provider, html, prelude::*, web_sys::Element};
 
 use crate::{
     components::{Description, SectionHeadingView},
     name::Name,
-    FormConfiguration,
+    FormConfiguration, FormContext,
 };
 
+/// Renders a section whose content is split into pages, of which one is shown at a time.
+///
+/// Hidden pages stay rendered, so their fields keep reporting their errors to the form.
+/// Focusing a field of a hidden page, e.g. after a failed submit, switches to its page.
 #[component]
 pub fn PaginatedSection(
     #[prop(into)] name: Name,
@@ -17,8 +21,30 @@ pub fn PaginatedSection(
     pages: Vec<Box<dyn Fn() -> AnyView + Send + Sync>>,
 ) -> impl IntoView {
     let form_configuration = use_context::<FormConfiguration>().unwrap_or_default();
+    let form_context = FormContext::register(name.clone());
     let total_pages = pages.len();
     let current_page = RwSignal::new(0);
+    let page_refs = (0..total_pages)
+        .map(|_| NodeRef::<html::Div>::new())
+        .collect::<Vec<_>>();
+
+    form_context.register_reveal(Callback::new({
+        let page_refs = page_refs.clone();
+        move |element: Element| {
+            let page = page_refs.iter().position(|page_ref| {
+                page_ref
+                    .get_untracked()
+                    .is_some_and(|page| page.contains(Some(&element)))
+            });
+            match page {
+                Some(page) if page != current_page.get_untracked() => {
+                    current_page.set(page);
+                    true
+                }
+                _ => false,
+            }
+        }
+    }));
 
     view! {
         <div
@@ -45,13 +71,18 @@ pub fn PaginatedSection(
                 class="form-section-content"
                 style={columns.map(|cols| format!("display: grid; grid-template-columns: repeat({}, 1fr);", cols))}
             >
-                { move || {
-                    if let Some(page_fn) = pages.get(current_page.get()) {
-                        page_fn()
-                    } else {
-                        ().into_any()
-                    }
-                }}
+                <Provider value=form_context>
+                    { pages.into_iter().zip(page_refs).enumerate().map(|(index, (page_fn, page_ref))| view! {
+                        <div
+                            class="form-page"
+                            node_ref=page_ref
+                            // The fields of hidden pages stay registered with the form
+                            style={move || if current_page.get() == index { "display: contents;" } else { "display: none;" }}
+                        >
+                            {page_fn()}
+                        </div>
+                    }).collect_view() }
+                </Provider>
             </div>
             <div class="form-section-pagination-controls">
                 <button
@@ -73,3 +104,45 @@ pub fn PaginatedSection(
         </div>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FieldContext, FieldError};
+
+    #[test]
+    fn fields_of_hidden_pages_stay_registered() {
+        let owner = Owner::new();
+        owner.with(|| {
+            let form_context = FormContext::new(Name::from("form"));
+            provide_context(form_context);
+            let email = Name::from("form[steps][email]");
+
+            let pages: Vec<Box<dyn Fn() -> AnyView + Send + Sync>> = vec![
+                Box::new(|| ().into_any()),
+                Box::new({
+                    let email = email.clone();
+                    move || {
+                        let field = FieldContext::register(email.clone());
+                        field
+                            .error()
+                            .set(Some(FieldError::new(email.clone(), "Required")));
+                        ().into_any()
+                    }
+                }),
+            ];
+            let _view = view! { <PaginatedSection name="form[steps]" pages=pages /> };
+
+            // The invalid field is on the second page while the first one is shown
+            assert!(form_context.field(&email).is_some());
+            assert_eq!(
+                form_context
+                    .errors()
+                    .iter()
+                    .map(|error| error.name())
+                    .collect::<Vec<_>>(),
+                [email]
+            );
+        });
+    }
+}
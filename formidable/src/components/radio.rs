@@ -3,7 +3,7 @@ use std::fmt::Display;
 use leptos::prelude::*;
 
 use crate::strum::VariantArray;
-use crate::{components::Description, Name};
+use crate::{components::{label::label_style, Description}, persist_draft_value, FieldContext, Name};
 
 #[component]
 pub fn Radio<T>(
@@ -26,13 +26,22 @@ where
         + Sync
         + 'static,
 {
+    let field_context = FieldContext::register(name.clone());
+    field_context.label().set(Some(label.get().to_string()));
+    let disabled = field_context.disabled();
+    let dirty = field_context.dirty();
+    persist_draft_value(name.clone(), move || {
+        let variant: &'static str = value.get().into();
+        variant.to_string()
+    });
+
     view! {
         <div
             class={format!("field radio-group-field{}", class.as_ref().map(|c| format!(" {}", c)).unwrap_or_default())}
             style={colspan.map(|cols| format!("grid-column: span {};", cols))}
         >
             <fieldset>
-                <legend>{label.get()}</legend>
+                <legend style=label_style()>{label.get()}</legend>
                 { <T as VariantArray>::VARIANTS.iter().map(move |&option| {
                     let is_checked = move || value.get() == option;
                     let option_value: &'static str = option.into();
@@ -47,7 +56,9 @@ where
                                     id=format!("{}-{}", name.to_string(), option_value)
                                     value=option_value
                                     checked=is_checked
+                                    disabled=move || disabled.get()
                                     on:change=move |_| {
+                                        dirty.set(true);
                                         value.set(option);
                                     }
                                 />
@@ -1,9 +1,9 @@
-use leptos::prelude::*;
+use leptos::{context::Provider, prelude::*};
 
 use crate::{
     components::{section_heading_view::SectionHeadingView, Description},
     name::Name,
-    FormConfiguration,
+    FormConfiguration, FormContext,
 };
 
 #[component]
@@ -17,6 +17,7 @@ pub fn Section(
     children: Children,
 ) -> impl IntoView {
     let form_configuration = use_context::<FormConfiguration>().unwrap_or_default();
+    let form_context = FormContext::register(name.clone());
 
     view! {
         <div
@@ -36,7 +37,9 @@ pub fn Section(
                 class="form-section-content"
                 style={columns.map(|cols| format!("display: grid; grid-template-columns: repeat({}, 1fr);", cols))}
             >
-                { children() }
+                <Provider value=form_context>
+                    { children() }
+                </Provider>
             </div>
         </div>
     }
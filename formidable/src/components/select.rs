@@ -3,7 +3,7 @@ use std::fmt::Display;
 use leptos::prelude::*;
 
 use crate::strum::VariantArray;
-use crate::{components::Description, Name};
+use crate::{components::{label::label_style, Description}, persist_draft_value, FieldContext, Name};
 
 #[component]
 pub fn Select<T>(
@@ -26,16 +26,27 @@ where
         + Sync
         + 'static,
 {
+    let field_context = FieldContext::register(name.clone());
+    field_context.label().set(Some(label.get().to_string()));
+    let disabled = field_context.disabled();
+    let dirty = field_context.dirty();
+    persist_draft_value(name.clone(), move || {
+        let variant: &'static str = value.get().into();
+        variant.to_string()
+    });
+
     view! {
         <div
             class={format!("field select-field{}", class.as_ref().map(|c| format!(" {}", c)).unwrap_or_default())}
             style={colspan.map(|cols| format!("grid-column: span {};", cols))}
         >
-            <label for=name.to_string()>{label.get()}</label>
+            <label for=name.to_string() style=label_style()>{label.get()}</label>
             <select
                 name=name.to_string()
                 id=name.to_string()
+                disabled=move || disabled.get()
                 on:change=move |ev| {
+                    dirty.set(true);
                     let selected_value = event_target_value(&ev);
                     // Find the variant that matches the selected value
                     if let Some(&option) = T::VARIANTS.iter().find(|&&variant| {
@@ -0,0 +1,95 @@
+use leptos::prelude::*;
+
+use crate::{
+    components::SectionHeadingView, name::Name, t, FormConfiguration, FormError, FormMessage,
+};
+
+#[component]
+pub fn SummarySection(
+    #[prop(into)] name: Name,
+    #[prop(into, default = None)] heading: Option<TextProp>,
+    #[prop(into, default = None)] class: Option<String>,
+    #[prop(into, default = None)] columns: Option<u32>,
+    #[prop(into, default = None)] colspan: Option<u32>,
+    children: Children,
+) -> impl IntoView {
+    let form_configuration = use_context::<FormConfiguration>().unwrap_or_default();
+
+    view! {
+        <div
+            class={format!("form-section summary-section{}", class.as_ref().map(|c| format!(" {}", c)).unwrap_or_default())}
+            style={colspan.map(|cols| format!("grid-column: span {};", cols))}
+        >
+            <div class="form-section-heading">
+                <SectionHeadingView
+                    heading={heading}
+                    section_label={form_configuration.section_label}
+                    name_len={name.len()}
+                />
+            </div>
+            <div
+                class="form-section-content"
+                style={columns.map(|cols| format!("display: grid; grid-template-columns: repeat({}, 1fr);", cols))}
+            >
+                { children() }
+            </div>
+        </div>
+    }
+}
+
+#[component]
+pub fn SummaryField(
+    #[prop(into, default = None)] label: Option<TextProp>,
+    #[prop(into)] value: String,
+    #[prop(into, default = None)] class: Option<String>,
+    #[prop(into, default = None)] colspan: Option<u32>,
+) -> impl IntoView {
+    view! {
+        <dl
+            class={format!("field summary-field{}", class.as_ref().map(|c| format!(" {}", c)).unwrap_or_default())}
+            style={colspan.map(|cols| format!("grid-column: span {};", cols))}
+        >
+            { label.map(|label| view! { <dt>{label.get()}</dt> }) }
+            <dd>{value}</dd>
+        </dl>
+    }
+}
+
+/// The summary of a field without a valid value, e.g. on the review page of a form that was
+/// not completed. Shows the errors of the field, or that it was not provided.
+#[component]
+pub fn IncompleteSummaryField(
+    #[prop(into, default = None)] label: Option<TextProp>,
+    #[prop(into, default = None)] error: Option<FormError>,
+    #[prop(into, default = None)] class: Option<String>,
+    #[prop(into, default = None)] colspan: Option<u32>,
+) -> impl IntoView {
+    view! {
+        <dl
+            class={format!("field summary-field incomplete{}", class.as_ref().map(|c| format!(" {}", c)).unwrap_or_default())}
+            style={colspan.map(|cols| format!("grid-column: span {};", cols))}
+        >
+            { label.map(|label| view! { <dt>{label.get()}</dt> }) }
+            { match error {
+                Some(error) => view! {
+                    <dd>
+                        { error.into_iter().map(|error| view! {
+                            <p class="message error-message">{error.to_string()}</p>
+                        }).collect_view() }
+                    </dd>
+                }.into_any(),
+                None => view! { <dd>{t(FormMessage::NotProvided)}</dd> }.into_any(),
+            } }
+        </dl>
+    }
+}
+
+#[component]
+pub fn ReviewPage(children: Children) -> impl IntoView {
+    view! {
+        <div class="review-page">
+            <div class="section-label">{t(FormMessage::ReviewHeading)}</div>
+            { children() }
+        </div>
+    }
+}
@@ -0,0 +1,449 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use leptos::{
+    prelude::*,
+    wasm_bindgen::JsCast,
+    web_sys::{Element, HtmlElement, Node},
+};
+
+use crate::{FieldError, FormError, Name, ServerFieldErrors};
+
+/// Registry of the fields and sections rendered below a form or section.
+///
+/// Every `Formidable*` component, section and input registers itself, so the state of
+/// all fields can be queried by their `Name`.
+#[derive(Clone, Copy, PartialEq)]
+pub struct FormContext(RwSignal<FormContextInner>);
+
+pub struct FormContextInner {
+    name: Name,
+    children: HashMap<Name, FormContext>,
+    fields: HashMap<Name, FieldContext>,
+    submitted: RwSignal<bool>,
+    server_errors: RwSignal<Vec<String>>,
+    pending_checks: Vec<RwSignal<bool>>,
+    revealers: Vec<Callback<Element, bool>>,
+}
+
+impl FormContext {
+    pub fn new(name: Name) -> Self {
+        Self(RwSignal::new(FormContextInner {
+            name,
+            children: HashMap::new(),
+            fields: HashMap::new(),
+            submitted: RwSignal::new(false),
+            server_errors: RwSignal::new(Vec::new()),
+            pending_checks: Vec::new(),
+            revealers: Vec::new(),
+        }))
+    }
+
+    /// Registers a section with the nearest `FormContext`, or creates a detached one
+    /// when rendered outside of a form.
+    pub fn register(name: Name) -> Self {
+        match use_context::<FormContext>() {
+            Some(parent) => parent.new_child(name),
+            None => FormContext::new(name),
+        }
+    }
+
+    pub fn new_field(&self, name: Name) -> FieldContext {
+        let this = FieldContext::new(name.clone());
+
+        let parent = self.0;
+
+        parent.update(|inner: &mut FormContextInner| {
+            inner.fields.insert(name.clone(), this);
+        });
+
+        // An item moved to a new index registers its name before the old one is cleaned up
+        on_cleanup(move || {
+            parent.try_update(|inner| {
+                if inner.fields.get(&name) == Some(&this) {
+                    inner.fields.remove(&name);
+                }
+            });
+        });
+
+        this
+    }
+
+    /// Registers the pending state of an async check, e.g. of an `AsyncValidator`, which
+    /// keeps the form from being submitted while it is `true`.
+    pub fn register_pending(&self, pending: RwSignal<bool>) {
+        let parent = self.0;
+
+        parent.update(|inner| inner.pending_checks.push(pending));
+
+        on_cleanup(move || {
+            parent.try_update(|inner| inner.pending_checks.retain(|check| *check != pending));
+        });
+    }
+
+    /// Registers a callback that makes an element of a hidden part of the form visible, e.g.
+    /// by switching to the page of a `PaginatedSection` that holds it. Returns whether it
+    /// changed anything.
+    pub fn register_reveal(&self, reveal: Callback<Element, bool>) {
+        let parent = self.0;
+
+        parent.update(|inner| inner.revealers.push(reveal));
+
+        on_cleanup(move || {
+            parent.try_update(|inner| inner.revealers.retain(|other| !other.matches(&reveal)));
+        });
+    }
+
+    /// Makes `element` visible if it is in a hidden part of the form. Returns whether
+    /// anything changed.
+    fn reveal(&self, element: &Element) -> bool {
+        let (revealers, children) = self.0.with_untracked(|inner| {
+            (
+                inner.revealers.clone(),
+                inner.children.values().copied().collect::<Vec<_>>(),
+            )
+        });
+        // Every revealer runs, so nested hidden parts are all revealed
+        let mut revealed = false;
+        for reveal in revealers {
+            revealed |= reveal.run(element.clone());
+        }
+        for child in children {
+            revealed |= child.reveal(element);
+        }
+        revealed
+    }
+
+    pub fn new_child(&self, name: Name) -> FormContext {
+        let this = Self::new(name.clone());
+
+        let parent = self.0;
+
+        parent.update(|inner| {
+            inner.children.insert(name.clone(), this);
+        });
+
+        on_cleanup(move || {
+            parent.try_update(|inner| {
+                if inner.children.get(&name) == Some(&this) {
+                    inner.children.remove(&name);
+                }
+            });
+        });
+
+        this
+    }
+
+    pub fn name(&self) -> Name {
+        self.0.with_untracked(|inner| inner.name.clone())
+    }
+
+    /// Whether the user has tried to submit the form.
+    pub fn submitted(&self) -> RwSignal<bool> {
+        self.0.with_untracked(|inner| inner.submitted)
+    }
+
+    /// The errors reported by the server that don't belong to any field of this form, e.g.
+    /// the message of a failed server function.
+    pub fn server_errors(&self) -> RwSignal<Vec<String>> {
+        self.0.with_untracked(|inner| inner.server_errors)
+    }
+
+    /// All fields registered in this context and its child sections.
+    pub fn fields(&self) -> Vec<FieldContext> {
+        self.0.with(|inner| {
+            inner
+                .fields
+                .values()
+                .copied()
+                .chain(inner.children.values().flat_map(|child| child.fields()))
+                .collect()
+        })
+    }
+
+    pub fn field(&self, name: &Name) -> Option<FieldContext> {
+        self.0.with(|inner| {
+            inner
+                .fields
+                .get(name)
+                .copied()
+                .or_else(|| inner.children.values().find_map(|child| child.field(name)))
+        })
+    }
+
+    pub fn is_touched(&self, name: &Name) -> bool {
+        self.field(name)
+            .map(|field| field.touched().get())
+            .unwrap_or(false)
+    }
+
+    pub fn is_disabled(&self, name: &Name) -> bool {
+        self.field(name)
+            .map(|field| field.disabled().get())
+            .unwrap_or(false)
+    }
+
+    pub fn error(&self, name: &Name) -> Option<FieldError> {
+        self.field(name).and_then(|field| field.error().get())
+    }
+
+    /// Whether an async validation of any field is outstanding.
+    pub fn is_pending(&self) -> bool {
+        self.0.with(|inner| {
+            inner.pending_checks.iter().any(|pending| pending.get())
+                || inner.children.values().any(|child| child.is_pending())
+        })
+    }
+
+    /// Whether any field has been changed by the user.
+    pub fn is_dirty(&self) -> bool {
+        self.fields().iter().any(|field| field.dirty().get())
+    }
+
+    /// Marks all fields as touched, which reveals their validation errors.
+    pub fn touch_all(&self) {
+        for field in untrack(|| self.fields()) {
+            field.touched().set(true);
+        }
+    }
+
+    /// Shows the errors reported by the server under their fields. Returns the errors
+    /// that don't belong to any field of this form, which are also kept in
+    /// [`FormContext::server_errors`].
+    pub fn set_server_errors(&self, errors: &ServerFieldErrors) -> Vec<String> {
+        let fields = untrack(|| self.fields());
+        for field in &fields {
+            field.server_error().set(None);
+        }
+
+        let mut unmatched = errors.form_errors().map(String::from).collect::<Vec<_>>();
+        for (name, message) in errors.field_errors() {
+            match fields.iter().find(|field| field.name() == *name) {
+                Some(field) => field.server_error().set(Some(message.to_string())),
+                None => unmatched.push(message.to_string()),
+            }
+        }
+
+        self.server_errors().set(unmatched.clone());
+        unmatched
+    }
+
+    /// The fields that currently show an error, in document order. Fields without an input
+    /// in the document come last.
+    pub fn invalid_fields(&self) -> Vec<FieldContext> {
+        let fields = self
+            .fields()
+            .into_iter()
+            .filter(|field| {
+                field.error().get().is_some()
+                    || field.async_error().get().is_some()
+                    || field.server_error().get().is_some()
+            })
+            .collect();
+
+        in_document_order(fields, FieldContext::name)
+    }
+
+    /// The message of every field error, with the errors reported by the server in place of
+    /// the validation errors of their fields, in document order.
+    pub fn field_error_messages(&self) -> Vec<(Name, String)> {
+        let mut errors = self
+            .errors()
+            .iter()
+            .map(|error| (error.name(), error.to_string()))
+            .collect::<Vec<_>>();
+        for field in self.fields() {
+            if let Some(message) = field.server_error().get() {
+                let name = field.name();
+                errors.retain(|(other, _)| *other != name);
+                errors.push((name, message));
+            }
+        }
+
+        in_document_order(errors, |(name, _)| name.clone())
+    }
+
+    /// Scrolls to and focuses the first invalid field in document order, and returns its name.
+    pub fn focus_first_error(&self) -> Option<Name> {
+        let name = untrack(|| self.invalid_fields()).first()?.name();
+        self.focus_field(&name);
+        Some(name)
+    }
+
+    /// Scrolls to and focuses the input of the field with the given name.
+    pub fn focus_field(&self, name: &Name) -> bool {
+        match field_element(name) {
+            Some(element) => {
+                if self.reveal(&element) {
+                    // Revealed elements are only focusable once they are shown
+                    request_animation_frame(move || focus_element(&element));
+                } else {
+                    focus_element(&element);
+                }
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// The errors of all currently invalid fields.
+    pub fn errors(&self) -> FormError {
+        FormError::from(
+            self.fields()
+                .into_iter()
+                .filter_map(|field| {
+                    field.error().get().or_else(|| {
+                        field
+                            .async_error()
+                            .get()
+                            .map(|message| FieldError::new(field.name(), message))
+                    })
+                })
+                .collect::<Vec<_>>(),
+        )
+    }
+}
+
+/// The element of the field with the given name: the input with the name as its `id`, or the
+/// first input named after it, e.g. the first checkbox of a `MultiSelect`.
+fn field_element(name: &Name) -> Option<Element> {
+    let name = name.to_string();
+    let document = document();
+    document.get_element_by_id(&name).or_else(|| {
+        document
+            .get_elements_by_name(&name)
+            .get(0)
+            .and_then(|node| node.dyn_into::<Element>().ok())
+    })
+}
+
+/// Sorts `items` by the position of the input of the field they belong to. Items of fields
+/// without an input in the document, e.g. of sections that aren't rendered, come last.
+fn in_document_order<T>(items: Vec<T>, name: impl Fn(&T) -> Name) -> Vec<T> {
+    let mut items = items
+        .into_iter()
+        .map(|item| (field_element(&name(&item)), item))
+        .collect::<Vec<_>>();
+
+    items.sort_by(|(a, _), (b, _)| match (a, b) {
+        (Some(a), Some(b)) => document_order(a, b),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    });
+
+    items.into_iter().map(|(_, item)| item).collect()
+}
+
+/// Orders two elements by their position in the document.
+fn document_order(a: &Node, b: &Node) -> Ordering {
+    if a.is_same_node(Some(b)) {
+        return Ordering::Equal;
+    }
+    let position = a.compare_document_position(b);
+    if position & Node::DOCUMENT_POSITION_FOLLOWING != 0 {
+        Ordering::Less
+    } else if position & Node::DOCUMENT_POSITION_PRECEDING != 0 {
+        Ordering::Greater
+    } else {
+        Ordering::Equal
+    }
+}
+
+fn focus_element(element: &Element) {
+    element.scroll_into_view();
+    if let Some(element) = element.dyn_ref::<HtmlElement>() {
+        let _ = element.focus();
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub struct FieldContext(RwSignal<FieldContextInner>);
+
+pub struct FieldContextInner {
+    pub name: Name,
+    pub label: RwSignal<Option<String>>,
+    pub error: RwSignal<Option<FieldError>>,
+    pub server_error: RwSignal<Option<String>>,
+    pub async_error: RwSignal<Option<String>>,
+    pub validation_pending: RwSignal<bool>,
+    pub touched: RwSignal<bool>,
+    pub dirty: RwSignal<bool>,
+    pub disabled: RwSignal<bool>,
+    pub optional: RwSignal<bool>,
+}
+
+impl FieldContext {
+    pub fn new(name: Name) -> Self {
+        FieldContext(RwSignal::new(FieldContextInner {
+            name,
+            label: RwSignal::new(None),
+            error: RwSignal::new(None),
+            server_error: RwSignal::new(None),
+            async_error: RwSignal::new(None),
+            validation_pending: RwSignal::new(false),
+            touched: RwSignal::new(false),
+            dirty: RwSignal::new(false),
+            disabled: RwSignal::new(false),
+            optional: RwSignal::new(false),
+        }))
+    }
+
+    /// Registers a field with the nearest `FormContext`, or creates a detached one
+    /// when rendered outside of a form.
+    pub fn register(name: Name) -> Self {
+        match use_context::<FormContext>() {
+            Some(form_context) => form_context.new_field(name),
+            None => FieldContext::new(name),
+        }
+    }
+
+    pub fn name(&self) -> Name {
+        self.0.with_untracked(|inner| inner.name.clone())
+    }
+
+    /// The label of the field, used to refer to the field e.g. in the `ErrorSummary`.
+    pub fn label(&self) -> RwSignal<Option<String>> {
+        self.0.with_untracked(|inner| inner.label)
+    }
+
+    pub fn error(&self) -> RwSignal<Option<FieldError>> {
+        self.0.with_untracked(|inner| inner.error)
+    }
+
+    /// Error reported by the server for the last submitted value of this field.
+    pub fn server_error(&self) -> RwSignal<Option<String>> {
+        self.0.with_untracked(|inner| inner.server_error)
+    }
+
+    /// Error of the async validation of the current value of this field.
+    pub fn async_error(&self) -> RwSignal<Option<String>> {
+        self.0.with_untracked(|inner| inner.async_error)
+    }
+
+    /// Whether an async validation of the current value of this field is outstanding.
+    pub fn validation_pending(&self) -> RwSignal<bool> {
+        self.0.with_untracked(|inner| inner.validation_pending)
+    }
+
+    pub fn touched(&self) -> RwSignal<bool> {
+        self.0.with_untracked(|inner| inner.touched)
+    }
+
+    /// Whether the user has changed the value of this field.
+    pub fn dirty(&self) -> RwSignal<bool> {
+        self.0.with_untracked(|inner| inner.dirty)
+    }
+
+    pub fn disabled(&self) -> RwSignal<bool> {
+        self.0.with_untracked(|inner| inner.disabled)
+    }
+
+    pub fn optional(&self) -> RwSignal<bool> {
+        self.0.with_untracked(|inner| inner.optional)
+    }
+}
+
+/// Returns the `FormContext` of the surrounding form or section, if any.
+pub fn use_form_context() -> Option<FormContext> {
+    use_context::<FormContext>()
+}
@@ -0,0 +1,388 @@
+use std::{
+    collections::{HashMap, HashSet},
+    time::Duration,
+};
+
+use leptos::prelude::*;
+
+use crate::{FormContext, FormFields, Name};
+
+/// Where a draft is persisted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum PersistStorage {
+    /// Kept until the draft is submitted or expires.
+    #[default]
+    Local,
+    /// Kept until the browser tab is closed.
+    Session,
+}
+
+/// Configuration for persisting the partial state of a form, so it survives reloads.
+///
+/// Created from the storage key, e.g. `persist="sign_up"`, or with further options:
+///
+/// ```rust,ignore
+/// persist=Persist::new("sign_up").session().expiry(Duration::from_secs(60 * 60))
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Persist {
+    key: String,
+    storage: PersistStorage,
+    expiry: Option<Duration>,
+}
+
+impl Persist {
+    pub fn new(key: impl Into<String>) -> Self {
+        Persist {
+            key: key.into(),
+            storage: PersistStorage::default(),
+            expiry: None,
+        }
+    }
+
+    /// Persist to `sessionStorage` instead of `localStorage`.
+    pub fn session(mut self) -> Self {
+        self.storage = PersistStorage::Session;
+        self
+    }
+
+    /// Discard drafts older than `expiry`.
+    pub fn expiry(mut self, expiry: Duration) -> Self {
+        self.expiry = Some(expiry);
+        self
+    }
+
+    /// The key the draft is stored under, namespaced to avoid collisions with other data.
+    pub fn storage_key(&self) -> String {
+        format!("formidable:{}", self.key)
+    }
+}
+
+impl From<&str> for Persist {
+    fn from(key: &str) -> Self {
+        Persist::new(key)
+    }
+}
+
+impl From<String> for Persist {
+    fn from(key: String) -> Self {
+        Persist::new(key)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct StoredDraft {
+    /// Milliseconds since the Unix epoch
+    saved_at: f64,
+    fields: FormFields,
+}
+
+/// The raw values of all fields of a form, including invalid input and selected enum
+/// variants. Used to persist drafts, to undo changes and to render rearranged items again.
+#[derive(Clone, Copy)]
+pub(crate) struct Draft {
+    persist: StoredValue<Option<Persist>>,
+    values: RwSignal<FormFields>,
+    restoring: StoredValue<bool>,
+    /// The number of mounted fields recording each name.
+    mounted: StoredValue<HashMap<Name, usize>>,
+    /// Names whose values are only kept in memory, e.g. of password inputs.
+    private: StoredValue<HashSet<Name>>,
+}
+
+impl Draft {
+    pub(crate) fn new(persist: Option<Persist>, form_context: FormContext) -> Self {
+        let draft = Draft {
+            persist: StoredValue::new(persist.clone()),
+            ..Draft::in_memory()
+        };
+
+        if persist.is_some() {
+            #[cfg(not(feature = "serde"))]
+            leptos::logging::warn!("Persisting drafts requires the `serde` feature");
+
+            // Only save drafts the user actually changed
+            Effect::new(move |_| {
+                draft.values.track();
+                if form_context.is_dirty() {
+                    draft.save();
+                }
+            });
+        }
+
+        draft
+    }
+
+    /// A draft that is only kept in memory, e.g. for the items of a list rendered outside of
+    /// a form.
+    pub(crate) fn in_memory() -> Self {
+        Draft {
+            persist: StoredValue::new(None),
+            values: RwSignal::new(FormFields::new()),
+            restoring: StoredValue::new(false),
+            mounted: StoredValue::new(HashMap::new()),
+            private: StoredValue::new(HashSet::new()),
+        }
+    }
+
+    pub(crate) fn values(&self) -> RwSignal<FormFields> {
+        self.values
+    }
+
+    /// Loads a saved draft. Returns whether the form has to be re-rendered to show it.
+    pub(crate) fn restore(&self) -> bool {
+        match self.load() {
+            Some(values) => {
+                self.apply(values);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Makes the fields use `values` when the form is rendered again. Fields mounted after
+    /// that render start out empty again.
+    pub(crate) fn apply(&self, values: FormFields) {
+        self.restoring.set_value(true);
+        self.values.set(values);
+        let restoring = self.restoring;
+        request_animation_frame(move || {
+            restoring.try_set_value(false);
+        });
+    }
+
+    /// Moves the values of the items of `name`, see [`FormFields::reindex`].
+    pub(crate) fn reindex(&self, name: &Name, order: &[Option<usize>]) {
+        self.values
+            .update(|values| *values = values.reindex(name, order));
+    }
+
+    /// Moves the values of `from` and the fields below it to `to`, see [`FormFields::rename`].
+    pub(crate) fn rename(&self, from: &Name, to: &Name) {
+        self.values
+            .update(|values| *values = values.rename(from, to));
+    }
+
+    /// A draft sharing the values of this one which restores them, so items rendered again at
+    /// a new index keep their raw values.
+    pub(crate) fn restoring(&self) -> Draft {
+        Draft {
+            restoring: StoredValue::new(true),
+            ..*self
+        }
+    }
+
+    /// Stops restoring values, e.g. because the form was reset.
+    pub(crate) fn discard(&self) {
+        self.restoring.set_value(false);
+    }
+
+    /// Removes the saved draft, e.g. after the form was submitted successfully.
+    pub(crate) fn clear(&self) {
+        self.restoring.set_value(false);
+        self.values.set(FormFields::new());
+        if let Some((storage, key)) = self.storage() {
+            let _ = storage.remove_item(&key);
+        }
+    }
+
+    /// The values that are persisted, i.e. all values except the private ones.
+    #[cfg(any(test, feature = "serde"))]
+    fn persisted_values(&self) -> FormFields {
+        let mut values = self.values.get_untracked();
+        self.private.with_value(|private| {
+            for name in private {
+                values.remove(name);
+            }
+        });
+        values
+    }
+
+    fn mount(&self, name: Name) {
+        self.mounted
+            .update_value(|mounted| *mounted.entry(name).or_default() += 1);
+    }
+
+    /// Removes the value of `name` once no field records it anymore, e.g. because an `Option`
+    /// field was unchecked. Fields rendered again, e.g. at a new index, are mounted before the
+    /// next frame and keep their values.
+    fn unmount(&self, name: Name) {
+        let unmounted = self
+            .mounted
+            .try_update_value(|mounted| match mounted.get_mut(&name) {
+                Some(count) => {
+                    *count -= 1;
+                    *count == 0
+                }
+                None => false,
+            });
+        if unmounted != Some(true) {
+            return;
+        }
+
+        let draft = *self;
+        request_animation_frame(move || {
+            let removed = draft.mounted.try_update_value(|mounted| {
+                let removed = mounted.get(&name) == Some(&0);
+                if removed {
+                    mounted.remove(&name);
+                }
+                removed
+            });
+            if removed == Some(true) {
+                draft.values.try_update(|values| values.remove(&name));
+            }
+        });
+    }
+
+    /// The storage and key the draft is persisted to, if it is persisted.
+    fn storage(&self) -> Option<(leptos::web_sys::Storage, String)> {
+        let (storage, key) = self.persist.with_value(|persist| {
+            persist
+                .as_ref()
+                .map(|persist| (persist.storage, persist.storage_key()))
+        })?;
+        let window = window();
+        let storage = match storage {
+            PersistStorage::Local => window.local_storage(),
+            PersistStorage::Session => window.session_storage(),
+        }
+        .ok()
+        .flatten()?;
+        Some((storage, key))
+    }
+
+    #[cfg(feature = "serde")]
+    fn load(&self) -> Option<FormFields> {
+        let (storage, key) = self.storage()?;
+        let stored = storage.get_item(&key).ok().flatten()?;
+
+        let draft = match serde_json::from_str::<StoredDraft>(&stored) {
+            Ok(draft) => draft,
+            Err(_) => {
+                let _ = storage.remove_item(&key);
+                return None;
+            }
+        };
+
+        let expired = self
+            .persist
+            .with_value(|persist| persist.as_ref().and_then(|persist| persist.expiry))
+            .is_some_and(|expiry| {
+                leptos::web_sys::js_sys::Date::now() - draft.saved_at > expiry.as_millis() as f64
+            });
+        if expired {
+            let _ = storage.remove_item(&key);
+            return None;
+        }
+
+        Some(draft.fields)
+    }
+
+    #[cfg(not(feature = "serde"))]
+    fn load(&self) -> Option<FormFields> {
+        None
+    }
+
+    #[cfg(feature = "serde")]
+    fn save(&self) {
+        let Some((storage, key)) = self.storage() else {
+            return;
+        };
+
+        let values = self.persisted_values();
+        if values.is_empty() {
+            let _ = storage.remove_item(&key);
+            return;
+        }
+
+        let draft = StoredDraft {
+            saved_at: leptos::web_sys::js_sys::Date::now(),
+            fields: values,
+        };
+        if let Ok(stored) = serde_json::to_string(&draft) {
+            let _ = storage.set_item(&key, &stored);
+        }
+    }
+
+    #[cfg(not(feature = "serde"))]
+    fn save(&self) {}
+}
+
+/// The value of a field in the draft that is being restored, if any.
+///
+/// Fields with a custom `Form` implementation use this as their initial value, and report
+/// their value with [`persist_draft_value`], to support the `persist` and `history` options
+/// of the forms.
+pub fn draft_value(name: &Name) -> Option<String> {
+    let draft = use_context::<Draft>()?;
+    if !draft.restoring.get_value() {
+        return None;
+    }
+    draft
+        .values
+        .with_untracked(|values| values.get(name).map(str::to_owned))
+}
+
+/// Records the raw value of a field in the draft of the surrounding form, if it is recorded.
+pub fn persist_draft_value(name: Name, value: impl Fn() -> String + Send + Sync + 'static) {
+    if let Some(draft) = use_context::<Draft>() {
+        // Effects only run in the browser, where the draft is recorded
+        Effect::new({
+            let name = name.clone();
+            move |_| draft.mount(name.clone())
+        });
+        on_cleanup({
+            let name = name.clone();
+            move || draft.unmount(name)
+        });
+        Effect::new(move |_| {
+            let value = value();
+            draft.values.update(|values| values.set(&name, value));
+        });
+    }
+}
+
+/// Keeps the raw value of a field out of the persisted draft of the surrounding form, e.g. of
+/// a password input. The value is still recorded in memory, e.g. to undo changes.
+pub(crate) fn keep_draft_value_private(name: Name) {
+    if let Some(draft) = use_context::<Draft>() {
+        draft.private.update_value(|private| {
+            private.insert(name);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn private_values_are_not_persisted() {
+        let owner = Owner::new();
+        owner.with(|| {
+            let draft = Draft::in_memory();
+            let password = Name::from("sign_up[password]");
+            let email = Name::from("sign_up[email]");
+            draft.values.update(|values| {
+                values.set(&password, "hunter2");
+                values.set(&email, "ada@example.com");
+            });
+            draft.private.update_value(|private| {
+                private.insert(password.clone());
+            });
+
+            let persisted = draft.persisted_values();
+            assert_eq!(persisted.get(&password), None);
+            assert_eq!(persisted.get(&email), Some("ada@example.com"));
+            // Private values are still available in memory, e.g. to undo changes
+            assert_eq!(
+                draft
+                    .values
+                    .with_untracked(|values| values.get(&password).map(str::to_owned)),
+                Some("hunter2".to_owned())
+            );
+        });
+    }
+}
@@ -60,13 +60,90 @@ impl FieldError {
     pub fn inner(&self) -> Arc<dyn Display + Send + Sync> {
         self.error.clone()
     }
+
+    /// The name of the field this error belongs to.
+    pub fn name(&self) -> Name {
+        self.name.clone()
+    }
+
+    /// The value the user entered, if the field is a text input.
+    pub fn raw_value(&self) -> Option<&str> {
+        match &self.raw_value {
+            RawValue::String(raw_value) => Some(raw_value),
+            RawValue::Other => None,
+        }
+    }
 }
 
-#[derive(Clone, Debug)]
+/// Errors are serialized with their message, and deserialized errors display this message.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct SerializedFieldError {
+    name: Name,
+    message: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    raw_value: Option<String>,
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for FieldError {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SerializedFieldError {
+            name: self.name.clone(),
+            message: self.error.to_string(),
+            raw_value: self.raw_value().map(str::to_owned),
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for FieldError {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let error = SerializedFieldError::deserialize(deserializer)?;
+        Ok(match error.raw_value {
+            Some(raw_value) => FieldError::new_string(error.name, error.message, raw_value),
+            None => FieldError::new(error.name, error.message),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct FormError {
     errors: Vec<FieldError>,
 }
 
+impl FormError {
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, FieldError> {
+        self.errors.iter()
+    }
+
+    /// The first error of the field with the given name.
+    pub fn get(&self, name: &Name) -> Option<&FieldError> {
+        self.errors.iter().find(|error| error.name == *name)
+    }
+
+    /// The errors of all fields at or below `prefix`, e.g. of all items of a `Vec`.
+    pub fn errors_under<'a>(&'a self, prefix: &'a Name) -> impl Iterator<Item = &'a FieldError> {
+        self.errors
+            .iter()
+            .filter(move |error| error.name.contains(prefix))
+    }
+}
+
 impl Display for FormError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         for err in &self.errors {
@@ -102,3 +179,117 @@ impl IntoIterator for FormError {
         self.errors.into_iter()
     }
 }
+
+impl<'a> IntoIterator for &'a FormError {
+    type Item = &'a FieldError;
+    type IntoIter = std::slice::Iter<'a, FieldError>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.iter()
+    }
+}
+
+impl std::error::Error for FormError {}
+
+/// Allows returning a `FormError` from server functions, e.g. the result of `Validate`.
+/// Errors of the server function itself are reported as an error without a field.
+#[cfg(feature = "serde")]
+impl leptos::server_fn::error::FromServerFnError for FormError {
+    type Encoder = leptos::server_fn::codec::JsonEncoding;
+
+    fn from_server_fn_error(value: leptos::server_fn::error::ServerFnErrorErr) -> Self {
+        FieldError::new(Name::default(), value.to_string()).into()
+    }
+}
+
+/// Errors reported by a server function, keyed by the `Name` of the field they belong to.
+///
+/// Return this error from a server function used with `FormidableServerAction` to show the
+/// errors under the matching fields. Errors without a matching field, and errors added via
+/// [`ServerFieldErrors::with_form_error`], are listed in the error summary of
+/// the form, or below the form if it has none.
+///
+/// ```rust,ignore
+/// #[server]
+/// async fn register(user_form: UserForm) -> Result<(), ServerFieldErrors> {
+///     if email_exists(&user_form.email).await {
+///         return Err(ServerFieldErrors::new()
+///             .with_field_error("user_form[email]", "Email already registered"));
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ServerFieldErrors {
+    fields: Vec<(Name, String)>,
+    form: Vec<String>,
+}
+
+impl ServerFieldErrors {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_field_error(mut self, name: impl Into<Name>, message: impl Into<String>) -> Self {
+        self.fields.push((name.into(), message.into()));
+        self
+    }
+
+    pub fn with_form_error(mut self, message: impl Into<String>) -> Self {
+        self.form.push(message.into());
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty() && self.form.is_empty()
+    }
+
+    pub fn field_errors(&self) -> impl Iterator<Item = (&Name, &str)> {
+        self.fields
+            .iter()
+            .map(|(name, message)| (name, message.as_str()))
+    }
+
+    pub fn form_errors(&self) -> impl Iterator<Item = &str> {
+        self.form.iter().map(String::as_str)
+    }
+}
+
+impl From<FormError> for ServerFieldErrors {
+    fn from(errors: FormError) -> Self {
+        errors
+            .into_iter()
+            .fold(ServerFieldErrors::new(), |server_errors, error| {
+                if error.name.is_empty() {
+                    server_errors.with_form_error(error.to_string())
+                } else {
+                    let message = error.to_string();
+                    server_errors.with_field_error(error.name, message)
+                }
+            })
+    }
+}
+
+impl Display for ServerFieldErrors {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for message in &self.form {
+            writeln!(f, "{}", message)?;
+        }
+        for (name, message) in &self.fields {
+            writeln!(f, "{}: {}", name, message)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ServerFieldErrors {}
+
+#[cfg(feature = "serde")]
+impl leptos::server_fn::error::FromServerFnError for ServerFieldErrors {
+    type Encoder = leptos::server_fn::codec::JsonEncoding;
+
+    fn from_server_fn_error(value: leptos::server_fn::error::ServerFnErrorErr) -> Self {
+        ServerFieldErrors::new().with_form_error(value.to_string())
+    }
+}
@@ -0,0 +1,300 @@
+use axum::{
+    body::Bytes,
+    extract::{FromRequest, Multipart, Request},
+    http::{header, StatusCode},
+    response::{Html, IntoResponse, Response},
+    Json,
+};
+use thiserror::Error;
+
+#[cfg(feature = "file")]
+use crate::types::File;
+use crate::{FormError, FormFields, FormName, FromFormFields, Name, NamePart, ServerFieldErrors};
+
+/// Axum extractor that decodes a form submitted as `application/x-www-form-urlencoded` or
+/// `multipart/form-data`, using the same field names as the rendered form.
+///
+/// The form must be rendered with the name of [`FormName`], e.g. from
+/// `#[form(name = "sign_up")]`. Fields of other names, such as CSRF tokens or the name of the
+/// submit button, are ignored. Values are validated while decoding, the same way the form
+/// validates them.
+///
+/// ```rust,ignore
+/// #[derive(Form, Clone, Debug)]
+/// #[form(name = "sign_up")]
+/// struct SignUp {
+///     // ...
+/// }
+///
+/// async fn sign_up(Formidable(sign_up): Formidable<SignUp>) -> impl IntoResponse {
+///     // ...
+/// }
+///
+/// let app = Router::new().route("/sign-up", post(sign_up));
+/// ```
+///
+/// Invalid submissions are rejected with a [`FormidableRejection`]. Use
+/// `Result<Formidable<T>, FormidableRejection>` as the extractor to handle them yourself,
+/// for example to render the form again with [`FormidableRejection::errors`] and the
+/// submitted values of [`FormidableRejection::fields`].
+#[derive(Debug, Clone)]
+pub struct Formidable<T>(pub T);
+
+#[derive(Debug, Error)]
+pub enum FormidableRejection {
+    #[error("Expected a form submission")]
+    UnsupportedMediaType,
+    #[error("Failed to read form submission: {0}")]
+    InvalidBody(String),
+    #[error("Invalid form submission")]
+    Invalid {
+        errors: FormError,
+        /// The submitted fields, keyed by their names.
+        fields: FormFields,
+        /// Whether the client prefers HTML, e.g. a browser submitting a form without JavaScript.
+        accepts_html: bool,
+        /// The page the form was submitted from, linked from the rendered errors.
+        referer: Option<String>,
+    },
+}
+
+impl FormidableRejection {
+    /// The validation errors of the submitted form, if the form was decoded but is invalid.
+    pub fn errors(&self) -> Option<&FormError> {
+        match self {
+            FormidableRejection::Invalid { errors, .. } => Some(errors),
+            _ => None,
+        }
+    }
+
+    /// The submitted fields, if the form was decoded but is invalid.
+    pub fn fields(&self) -> Option<&FormFields> {
+        match self {
+            FormidableRejection::Invalid { fields, .. } => Some(fields),
+            _ => None,
+        }
+    }
+}
+
+impl<T, S> FromRequest<S> for Formidable<T>
+where
+    T: FromFormFields + FormName,
+    S: Send + Sync,
+{
+    type Rejection = FormidableRejection;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let content_type = header_value(&req, header::CONTENT_TYPE);
+        let accepts_html = header_value(&req, header::ACCEPT).contains("text/html");
+        let referer =
+            Some(header_value(&req, header::REFERER)).filter(|referer| !referer.is_empty());
+
+        let fields = if content_type.starts_with("application/x-www-form-urlencoded") {
+            let body = Bytes::from_request(req, state)
+                .await
+                .map_err(|err| FormidableRejection::InvalidBody(err.body_text()))?;
+            FormFields::from_urlencoded(&body)
+        } else if content_type.starts_with("multipart/form-data") {
+            let multipart = Multipart::from_request(req, state)
+                .await
+                .map_err(|err| FormidableRejection::InvalidBody(err.body_text()))?;
+            read_multipart(multipart).await?
+        } else {
+            return Err(FormidableRejection::UnsupportedMediaType);
+        };
+
+        T::from_form_fields(&fields, Name::from(T::NAME))
+            .map(Formidable)
+            .map_err(|errors| FormidableRejection::Invalid {
+                errors,
+                fields,
+                accepts_html,
+                referer,
+            })
+    }
+}
+
+fn header_value(req: &Request, name: header::HeaderName) -> String {
+    req.headers()
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default()
+        .to_owned()
+}
+
+async fn read_multipart(mut multipart: Multipart) -> Result<FormFields, FormidableRejection> {
+    let mut fields = FormFields::new();
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(|err| FormidableRejection::InvalidBody(err.body_text()))?
+    {
+        let Some(name) = field.name().map(str::to_owned) else {
+            continue;
+        };
+        if let Some(file_name) = field.file_name().map(str::to_owned) {
+            read_file(&mut fields, name, file_name, field).await?;
+            continue;
+        }
+        let value = field
+            .text()
+            .await
+            .map_err(|err| FormidableRejection::InvalidBody(err.body_text()))?;
+        fields.insert(name, value);
+    }
+    Ok(fields)
+}
+
+#[cfg(feature = "file")]
+async fn read_file(
+    fields: &mut FormFields,
+    name: String,
+    file_name: String,
+    field: axum::extract::multipart::Field<'_>,
+) -> Result<(), FormidableRejection> {
+    // Browsers submit an empty file input as a file without a name
+    if file_name.is_empty() {
+        return Ok(());
+    }
+    let content_type = field.content_type().unwrap_or_default().to_owned();
+    let data = field
+        .bytes()
+        .await
+        .map_err(|err| FormidableRejection::InvalidBody(err.body_text()))?;
+    fields.insert_file(name, File::new(file_name, content_type, data.to_vec()));
+    Ok(())
+}
+
+// Without the `file` feature there is no type to decode uploaded files into
+#[cfg(not(feature = "file"))]
+async fn read_file(
+    _fields: &mut FormFields,
+    _name: String,
+    _file_name: String,
+    _field: axum::extract::multipart::Field<'_>,
+) -> Result<(), FormidableRejection> {
+    Ok(())
+}
+
+impl IntoResponse for FormidableRejection {
+    fn into_response(self) -> Response {
+        match self {
+            FormidableRejection::UnsupportedMediaType => {
+                (StatusCode::UNSUPPORTED_MEDIA_TYPE, self.to_string()).into_response()
+            }
+            FormidableRejection::InvalidBody(_) => {
+                (StatusCode::BAD_REQUEST, self.to_string()).into_response()
+            }
+            FormidableRejection::Invalid {
+                errors,
+                accepts_html: false,
+                ..
+            } => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                Json(ServerFieldErrors::from(errors)),
+            )
+                .into_response(),
+            FormidableRejection::Invalid {
+                errors,
+                accepts_html: true,
+                referer,
+                ..
+            } => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                Html(render_errors(errors, referer.as_deref())),
+            )
+                .into_response(),
+        }
+    }
+}
+
+/// Renders the errors the same way `FormidableServerAction` shows errors reported by the server,
+/// with the field each error belongs to and a link back to the form.
+fn render_errors(errors: FormError, referer: Option<&str>) -> String {
+    let errors = ServerFieldErrors::from(errors);
+    // Errors of the whole form come first, as they are shown below the form
+    let form_items = errors
+        .form_errors()
+        .map(|message| format!("<li>{}</li>", escape_html(message)));
+    let field_items = errors.field_errors().map(|(name, message)| {
+        format!(
+            r#"<li data-name="{}"><strong>{}</strong>: {}</li>"#,
+            escape_html(&name.to_string()),
+            escape_html(&field_label(name)),
+            escape_html(message)
+        )
+    });
+    let items = form_items.chain(field_items).collect::<String>();
+    let back = referer
+        .map(|referer| {
+            format!(
+                r#"<p><a href="{}">Back to the form</a></p>"#,
+                escape_html(referer)
+            )
+        })
+        .unwrap_or_default();
+    format!(
+        r#"<!DOCTYPE html><html><body><ul class="message error-message server-errors">{}</ul>{}</body></html>"#,
+        items, back
+    )
+}
+
+/// A readable label for the field with the given name, as the labels of the form aren't known
+/// on the server, e.g. "Addresses › 1 › Street" for `sign_up[addresses][0][street]`.
+fn field_label(name: &Name) -> String {
+    name.iter()
+        // The first part is the name of the form
+        .skip(1)
+        .map(|part| match part {
+            NamePart::Index(index) => (index + 1).to_string(),
+            NamePart::Key(key) => {
+                let key = key.replace('_', " ");
+                let mut chars = key.chars();
+                chars
+                    .next()
+                    .map(|first| first.to_uppercase().chain(chars).collect())
+                    .unwrap_or_default()
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" › ")
+}
+
+fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FieldError;
+
+    #[test]
+    fn render_errors_includes_form_errors() {
+        let errors = FormError::from(vec![
+            FieldError::new(Name::from("sign_up[email]"), "Invalid <email>"),
+            FieldError::new(Name::default(), "Passwords don't match"),
+        ]);
+        let html = render_errors(errors, None);
+        assert!(html.contains("<li>Passwords don't match</li>"));
+        assert!(html.contains(
+            r#"<li data-name="sign_up[email]"><strong>Email</strong>: Invalid &lt;email&gt;</li>"#
+        ));
+        assert!(!html.contains("<a "));
+    }
+
+    #[test]
+    fn render_errors_links_back_to_the_form() {
+        let errors = FormError::from(FieldError::new(
+            Name::from("sign_up[addresses][0][street_name]"),
+            "Required",
+        ));
+        let html = render_errors(errors, Some("/sign-up?step=2&plan=pro"));
+        assert!(html.contains("<strong>Addresses › 1 › Street name</strong>: Required"));
+        assert!(html.contains(r#"<a href="/sign-up?step=2&amp;plan=pro">Back to the form</a>"#));
+    }
+}
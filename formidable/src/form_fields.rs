@@ -0,0 +1,290 @@
+use std::collections::HashMap;
+
+use thiserror::Error;
+
+use crate::{FormError, Name};
+
+/// Fields of a submitted form, keyed by the string representation of their `Name`,
+/// e.g. `user_form[contact_info][email]`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct FormFields {
+    fields: HashMap<String, Vec<String>>,
+    /// Files uploaded with a `multipart/form-data` submission.
+    #[cfg(feature = "file")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    files: HashMap<String, crate::types::File>,
+}
+
+#[derive(Debug, Clone, Copy, Error, PartialEq, Eq, Hash)]
+pub enum FormFieldsError {
+    #[error("Invalid selection")]
+    InvalidVariant,
+    #[error("Cannot be submitted without JavaScript")]
+    Unsupported,
+}
+
+impl FormFields {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses an `application/x-www-form-urlencoded` body.
+    pub fn from_urlencoded(body: &[u8]) -> Self {
+        form_urlencoded::parse(body).into_owned().collect()
+    }
+
+    pub fn insert(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        self.fields
+            .entry(name.into())
+            .or_default()
+            .push(value.into());
+    }
+
+    /// Adds a file uploaded as `name`, replacing a previous file of the same name.
+    #[cfg(feature = "file")]
+    pub fn insert_file(&mut self, name: impl Into<String>, file: crate::types::File) {
+        self.files.insert(name.into(), file);
+    }
+
+    /// The file uploaded as `name`.
+    #[cfg(feature = "file")]
+    pub fn get_file(&self, name: &Name) -> Option<&crate::types::File> {
+        self.files.get(&name.to_string())
+    }
+
+    /// Replaces all values of `name` with `value`.
+    pub fn set(&mut self, name: &Name, value: impl Into<String>) {
+        self.fields.insert(name.to_string(), vec![value.into()]);
+    }
+
+    /// Removes all values of `name`.
+    pub(crate) fn remove(&mut self, name: &Name) {
+        self.fields.remove(&name.to_string());
+    }
+
+    pub fn is_empty(&self) -> bool {
+        #[cfg(feature = "file")]
+        if !self.files.is_empty() {
+            return false;
+        }
+        self.fields.is_empty()
+    }
+
+    /// The first value submitted for `name`.
+    pub fn get(&self, name: &Name) -> Option<&str> {
+        self.get_all(name).first().map(String::as_str)
+    }
+
+    /// All values submitted for `name`.
+    pub fn get_all(&self, name: &Name) -> &[String] {
+        self.fields
+            .get(&name.to_string())
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    pub fn contains(&self, name: &Name) -> bool {
+        let name = name.to_string();
+        self.keys().any(|key| *key == name)
+    }
+
+    /// The names of all submitted values and files.
+    fn keys(&self) -> impl Iterator<Item = &String> {
+        let keys = self.fields.keys();
+        #[cfg(feature = "file")]
+        let keys = keys.chain(self.files.keys());
+        keys
+    }
+
+    /// The sorted names of the submitted forms, i.e. the first parts of the submitted field
+    /// names.
+    pub fn root_names(&self) -> Vec<&str> {
+        let mut roots = self
+            .keys()
+            .map(|key| key.split('[').next().unwrap_or_default())
+            .collect::<Vec<_>>();
+        roots.sort_unstable();
+        roots.dedup();
+        roots
+    }
+
+    /// The sorted indices of all fields directly below `name`, e.g. `1` and `3`
+    /// for `list[1][title]` and `list[3]`.
+    pub fn indices(&self, name: &Name) -> Vec<usize> {
+        let prefix = format!("{}[", name);
+        let mut indices = self
+            .keys()
+            .filter_map(|key| key.strip_prefix(&prefix))
+            .filter_map(|rest| rest.split_once(']'))
+            .filter_map(|(index, _)| index.parse::<usize>().ok())
+            .collect::<Vec<_>>();
+        indices.sort_unstable();
+        indices.dedup();
+        indices
+    }
+
+    /// The sorted keys of all fields directly below `name`, e.g. `a` and `b`
+    /// for `map[a][title]` and `map[b]`.
+    pub fn keys_below(&self, name: &Name) -> Vec<String> {
+        let prefix = format!("{}[", name);
+        let mut keys = self
+            .keys()
+            .filter_map(|key| key.strip_prefix(&prefix))
+            .filter_map(|rest| rest.split_once(']'))
+            .map(|(key, _)| key.to_owned())
+            .collect::<Vec<_>>();
+        keys.sort_unstable();
+        keys.dedup();
+        keys
+    }
+
+    /// Moves the fields of `from` and below it to `to`.
+    pub(crate) fn rename(&self, from: &Name, to: &Name) -> FormFields {
+        let from = from.to_string();
+        let mut fields = FormFields::new();
+        for (key, values) in &self.fields {
+            let key = match key.strip_prefix(&from) {
+                Some(rest) if rest.is_empty() || rest.starts_with('[') => format!("{}{}", to, rest),
+                _ => key.clone(),
+            };
+            fields.fields.insert(key, values.clone());
+        }
+        fields
+    }
+
+    /// Moves the fields below the indices of `name` to new indices. `order[new]` is the old
+    /// index of the item now at `new`, or `None` for a new item without fields.
+    pub(crate) fn reindex(&self, name: &Name, order: &[Option<usize>]) -> FormFields {
+        let prefix = format!("{}[", name);
+        let mut fields = FormFields::new();
+        for (key, values) in &self.fields {
+            if !key.starts_with(&prefix) {
+                fields.fields.insert(key.clone(), values.clone());
+            }
+        }
+
+        for (new, old) in order.iter().enumerate() {
+            let Some(old) = old else {
+                continue;
+            };
+            let old_item = format!("{}[{}]", name, old);
+            for (key, values) in &self.fields {
+                if let Some(rest) = key.strip_prefix(&old_item) {
+                    if rest.is_empty() || rest.starts_with('[') {
+                        fields
+                            .fields
+                            .insert(format!("{}[{}]{}", name, new, rest), values.clone());
+                    }
+                }
+            }
+        }
+
+        fields
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for FormFields
+where
+    K: Into<String>,
+    V: Into<String>,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut fields = FormFields::new();
+        for (name, value) in iter {
+            fields.insert(name, value);
+        }
+        fields
+    }
+}
+
+/// Rebuilds a value from submitted form fields, using the same `Name` scheme as the form
+/// inputs. This allows handling forms that were submitted without JavaScript.
+///
+/// Implemented for all built-in types and generated by `#[derive(Form)]`, which requires it
+/// for the types of all fields. Types implementing `Form` by hand that can't be submitted
+/// without JavaScript can return [`FormFieldsError::Unsupported`].
+pub trait FromFormFields: Sized {
+    fn from_form_fields(fields: &FormFields, name: Name) -> Result<Self, FormError>;
+}
+
+/// The name a form is rendered with, which all of its field names start with.
+///
+/// Generated by `#[derive(Form)]` for types with `#[form(name = "...")]`.
+pub trait FormName {
+    const NAME: &'static str;
+}
+
+/// Decodes an `application/x-www-form-urlencoded` body submitted by a form with the given `name`.
+///
+/// ```rust,ignore
+/// let form_data = formidable::from_urlencoded::<FormData>(&body, "user_form")?;
+/// ```
+pub fn from_urlencoded<T: FromFormFields>(
+    body: &[u8],
+    name: impl Into<Name>,
+) -> Result<T, FormError> {
+    T::from_form_fields(&FormFields::from_urlencoded(body), name.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_urlencoded_decodes_names_and_values() {
+        let fields = FormFields::from_urlencoded(
+            b"sign_up%5Bname%5D=Ada+Lovelace&sign_up[tags]=a&sign_up[tags]=b&sign_up[empty]=",
+        );
+        let sign_up = Name::from("sign_up");
+        assert_eq!(fields.get(&sign_up.push_key("name")), Some("Ada Lovelace"));
+        assert_eq!(fields.get_all(&sign_up.push_key("tags")), ["a", "b"]);
+        assert_eq!(fields.get(&sign_up.push_key("empty")), Some(""));
+        assert_eq!(fields.get(&sign_up.push_key("missing")), None);
+        assert!(fields.contains(&sign_up.push_key("empty")));
+        assert_eq!(fields.root_names(), ["sign_up"]);
+    }
+
+    #[test]
+    fn keys_below_are_sorted_and_distinct() {
+        let fields = FormFields::from_urlencoded(
+            b"map[values][b][title]=x&map[values][a]=y&map[values][b][done]=on&map[keys][c]=z",
+        );
+        let values = Name::from("map").push_key("values");
+        assert_eq!(fields.keys_below(&values), ["a", "b"]);
+        assert_eq!(fields.keys_below(&Name::from("map")), ["keys", "values"]);
+    }
+
+    #[test]
+    fn rename_moves_fields_below_the_name() {
+        let fields = FormFields::from_iter([
+            ("map[values][a]", "1"),
+            ("map[values][a][b]", "2"),
+            ("map[values][ab]", "3"),
+        ]);
+        let values = Name::from("map").push_key("values");
+        let renamed = fields.rename(&values.push_key("a"), &values.push_key("c"));
+        assert_eq!(renamed.get(&Name::from("map[values][c]")), Some("1"));
+        assert_eq!(renamed.get(&Name::from("map[values][c][b]")), Some("2"));
+        assert_eq!(renamed.get(&Name::from("map[values][ab]")), Some("3"));
+        assert_eq!(renamed.get(&Name::from("map[values][a]")), None);
+    }
+
+    #[test]
+    fn indices_are_sorted_and_distinct() {
+        let fields = FormFields::from_urlencoded(
+            b"list[3][title]=c&list[1][title]=a&list[1][done]=on&list[10]=x&list[key]=y&other[2]=z",
+        );
+        assert_eq!(fields.indices(&Name::from("list")), [1, 3, 10]);
+        assert_eq!(
+            fields.indices(&Name::from("list").push_index(1)),
+            Vec::<usize>::new()
+        );
+        assert_eq!(fields.indices(&Name::from("missing")), Vec::<usize>::new());
+        assert_eq!(fields.root_names(), ["list", "other"]);
+    }
+}
@@ -0,0 +1,309 @@
+use leptos::{prelude::*, wasm_bindgen::JsCast, web_sys::HtmlElement};
+
+use crate::{Draft, FormContext, FormError, FormFields, History};
+
+/// Handle to control a `Formidable*` form from outside of the form, for example
+/// from "Load template" or "Clear form" buttons.
+///
+/// Create a handle with [`use_formidable`] and pass it to the form via its `handle` prop:
+///
+/// ```rust,ignore
+/// let handle = use_formidable::<FormData>();
+///
+/// view! {
+///     <button on:click=move |_| handle.reset()>"Clear form"</button>
+///     <FormidableCallback<FormData> label="Form" name="form" callback=callback handle=handle />
+/// }
+/// ```
+pub struct FormHandle<T: Send + Sync + 'static> {
+    // The value the form was initially rendered with, restored by `reset`
+    initial: RwSignal<Option<T>>,
+    // The value the form is currently rendered with, changing it re-renders the form
+    rendered: RwSignal<Option<T>>,
+    current: RwSignal<Option<Result<T, FormError>>>,
+    form_context: RwSignal<Option<FormContext>>,
+    on_submit: RwSignal<Option<Callback<()>>>,
+    draft: RwSignal<Option<Draft>>,
+    history: RwSignal<Option<History>>,
+}
+
+impl<T: Send + Sync + 'static> Clone for FormHandle<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Send + Sync + 'static> Copy for FormHandle<T> {}
+
+impl<T: Send + Sync + 'static> Default for FormHandle<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Send + Sync + 'static> FormHandle<T> {
+    pub fn new() -> Self {
+        FormHandle {
+            initial: RwSignal::new(None),
+            rendered: RwSignal::new(None),
+            current: RwSignal::new(None),
+            form_context: RwSignal::new(None),
+            on_submit: RwSignal::new(None),
+            draft: RwSignal::new(None),
+            history: RwSignal::new(None),
+        }
+    }
+
+    /// Connects the handle to a rendered form.
+    pub(crate) fn attach(
+        &self,
+        value: Option<T>,
+        form_context: FormContext,
+        on_submit: Option<Callback<()>>,
+    ) where
+        T: Clone,
+    {
+        self.initial.set(value.clone());
+        self.rendered.set(value);
+        self.current.set(None);
+        self.form_context.set(Some(form_context));
+        self.on_submit.set(on_submit);
+    }
+
+    /// Restores the draft saved for the form, if any, once the form is mounted.
+    pub(crate) fn attach_draft(&self, draft: Draft) {
+        self.draft.set(Some(draft));
+        let rendered = self.rendered;
+        Effect::new(move |_| {
+            if draft.restore() {
+                // Render the form again, now with the values of the draft
+                rendered.update(|_| {});
+            }
+        });
+    }
+
+    pub(crate) fn attach_history(&self, history: History) {
+        self.history.set(Some(history));
+    }
+
+    /// Removes the saved draft after the form was submitted successfully.
+    pub(crate) fn clear_draft(&self) {
+        if let Some(draft) = self.draft.get_untracked() {
+            draft.clear();
+        }
+    }
+
+    fn discard_draft(&self) {
+        if let Some(draft) = self.draft.get_untracked() {
+            draft.discard();
+        }
+    }
+
+    /// The value the form has to be rendered with.
+    pub(crate) fn rendered(&self) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.rendered.get()
+    }
+
+    pub(crate) fn set_result(&self, result: Result<T, FormError>) {
+        self.current.set(Some(result));
+    }
+
+    /// Resets the form to the value it was initially rendered with.
+    pub fn reset(&self)
+    where
+        T: Clone,
+    {
+        self.discard_draft();
+        self.rendered.set(self.initial.get_untracked());
+    }
+
+    /// Replaces the contents of the form with `value`.
+    pub fn set_value(&self, value: T) {
+        self.discard_draft();
+        self.rendered.set(Some(value));
+    }
+
+    /// The current value of the form, if it is valid. Values whose async validation is still
+    /// outstanding are not valid yet.
+    pub fn value(&self) -> Option<T>
+    where
+        T: Clone,
+    {
+        if self.is_pending() {
+            return None;
+        }
+        self.current.with(|current| {
+            current
+                .as_ref()
+                .and_then(|result| result.as_ref().ok().cloned())
+        })
+    }
+
+    /// Whether an async validation of any field is outstanding.
+    pub fn is_pending(&self) -> bool {
+        self.form_context
+            .with(|form_context| form_context.is_some_and(|form_context| form_context.is_pending()))
+    }
+
+    /// The errors of all currently invalid fields, and the errors of the current value that
+    /// belong to no field, e.g. of validating the fields of a struct against each other.
+    pub fn errors(&self) -> FormError {
+        let mut errors = self
+            .form_context
+            .get()
+            .map(|form_context| form_context.errors())
+            .unwrap_or_else(|| FormError::from(Vec::new()));
+        let unreported = self.current.with(|current| match current {
+            Some(Err(current)) => current
+                .iter()
+                .filter(|error| errors.get(&error.name()).is_none())
+                .cloned()
+                .collect::<Vec<_>>(),
+            _ => Vec::new(),
+        });
+        errors.extend(unreported);
+        errors
+    }
+
+    pub fn is_valid(&self) -> bool {
+        !self.is_pending() && self.current.with(|current| matches!(current, Some(Ok(_))))
+    }
+
+    /// Whether any field has been changed by the user since the form was last rendered.
+    pub fn is_dirty(&self) -> bool {
+        self.form_context
+            .get()
+            .map(|form_context| form_context.is_dirty())
+            .unwrap_or(false)
+    }
+
+    /// Marks all fields as touched, which reveals their validation errors.
+    pub fn touch_all(&self) {
+        if let Some(form_context) = self.form_context.get_untracked() {
+            form_context.touch_all();
+        }
+    }
+
+    /// Reverts the last change of the form, if the form has an undo `history`. Returns whether
+    /// there was a change to revert.
+    pub fn undo(&self) -> bool {
+        match self
+            .history
+            .get_untracked()
+            .and_then(|history| history.undo())
+        {
+            Some(snapshot) => {
+                self.show_snapshot(snapshot);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Restores the last change reverted by [`FormHandle::undo`]. Returns whether there was a
+    /// change to restore.
+    pub fn redo(&self) -> bool {
+        match self
+            .history
+            .get_untracked()
+            .and_then(|history| history.redo())
+        {
+            Some(snapshot) => {
+                self.show_snapshot(snapshot);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn can_undo(&self) -> bool {
+        self.history
+            .get()
+            .map(|history| history.can_undo())
+            .unwrap_or(false)
+    }
+
+    pub fn can_redo(&self) -> bool {
+        self.history
+            .get()
+            .map(|history| history.can_redo())
+            .unwrap_or(false)
+    }
+
+    /// Renders the form again with the raw values of `snapshot`, keeping the focused field.
+    fn show_snapshot(&self, snapshot: FormFields) {
+        let Some(draft) = self.draft.get_untracked() else {
+            return;
+        };
+
+        let focused = document()
+            .active_element()
+            .map(|element| element.id())
+            .filter(|id| !id.is_empty());
+
+        draft.apply(snapshot);
+        self.rendered.update(|_| {});
+
+        if let Some(focused) = focused {
+            request_animation_frame(move || {
+                if let Some(element) = document()
+                    .get_element_by_id(&focused)
+                    .and_then(|element| element.dyn_into::<HtmlElement>().ok())
+                {
+                    let _ = element.focus();
+                }
+            });
+        }
+    }
+
+    /// Submits the form the same way as its submit button does.
+    /// Forms without a submit action, such as `FormidableRwSignal`, ignore this.
+    pub fn submit(&self) {
+        if let Some(on_submit) = self.on_submit.get_untracked() {
+            on_submit.run(());
+        }
+    }
+}
+
+/// Returns the handle of the surrounding form of type `T`, or a new handle that can be
+/// passed to a form via its `handle` prop.
+pub fn use_formidable<T: Send + Sync + 'static>() -> FormHandle<T> {
+    use_context::<FormHandle<T>>().unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FieldError, Name};
+
+    #[test]
+    fn errors_include_errors_without_a_field() {
+        let owner = Owner::new();
+        owner.with(|| {
+            let handle = FormHandle::<String>::new();
+            let form_context = FormContext::new(Name::from("sign_up"));
+            handle.attach(None, form_context, None);
+
+            let email = Name::from("sign_up[email]");
+            let email_error = FieldError::new(email.clone(), "Invalid email");
+            form_context
+                .new_field(email.clone())
+                .error()
+                .set(Some(email_error.clone()));
+            // Validating the fields against each other reports an error of the whole struct
+            let struct_error = FieldError::new(Name::from("sign_up"), "Passwords don't match");
+            handle.set_result(Err(FormError::from(vec![email_error, struct_error])));
+
+            let names = handle
+                .errors()
+                .iter()
+                .map(|error| error.name())
+                .collect::<Vec<_>>();
+            assert_eq!(names, [email, Name::from("sign_up")]);
+            assert!(!handle.is_valid());
+        });
+    }
+}
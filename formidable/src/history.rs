@@ -0,0 +1,150 @@
+use std::time::Duration;
+
+use leptos::prelude::*;
+
+use crate::{Draft, FormContext, FormFields};
+
+/// Configuration for the undo history of a form, see [`FormHandle::undo`](crate::FormHandle::undo).
+///
+/// ```rust,ignore
+/// history=UndoHistory::new().keyboard_shortcuts()
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UndoHistory {
+    limit: usize,
+    coalesce: Duration,
+    keyboard_shortcuts: bool,
+}
+
+impl Default for UndoHistory {
+    fn default() -> Self {
+        UndoHistory {
+            limit: 100,
+            coalesce: Duration::from_secs(1),
+            keyboard_shortcuts: false,
+        }
+    }
+}
+
+impl UndoHistory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The maximum number of steps that can be undone.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Changes following each other within `coalesce`, e.g. keystrokes, are undone as one step.
+    pub fn coalesce(mut self, coalesce: Duration) -> Self {
+        self.coalesce = coalesce;
+        self
+    }
+
+    /// Undo with Ctrl+Z and redo with Ctrl+Shift+Z or Ctrl+Y (Cmd on macOS) inside the form,
+    /// instead of the undo of the browser, which only covers a single input.
+    pub fn keyboard_shortcuts(mut self) -> Self {
+        self.keyboard_shortcuts = true;
+        self
+    }
+
+    pub(crate) fn has_keyboard_shortcuts(&self) -> bool {
+        self.keyboard_shortcuts
+    }
+}
+
+/// Snapshots of the raw values of all fields, taken from the form's `Draft`.
+#[derive(Clone, Copy)]
+pub(crate) struct History {
+    undo_stack: RwSignal<Vec<FormFields>>,
+    redo_stack: RwSignal<Vec<FormFields>>,
+    current: StoredValue<FormFields>,
+    last_change: StoredValue<f64>,
+    applying: StoredValue<bool>,
+}
+
+impl History {
+    pub(crate) fn new(config: UndoHistory, draft: Draft, form_context: FormContext) -> Self {
+        let history = History {
+            undo_stack: RwSignal::new(Vec::new()),
+            redo_stack: RwSignal::new(Vec::new()),
+            current: StoredValue::new(FormFields::new()),
+            last_change: StoredValue::new(0.0),
+            applying: StoredValue::new(false),
+        };
+
+        Effect::new(move |_| {
+            let values = draft.values().get();
+            if history.current.with_value(|current| *current == values) {
+                return;
+            }
+
+            // Changes while rendering the form or applying a snapshot are no steps on their own
+            if history.applying.get_value() || !untrack(|| form_context.is_dirty()) {
+                history.current.set_value(values);
+                return;
+            }
+
+            let now = leptos::web_sys::js_sys::Date::now();
+            let since_last_change = now - history.last_change.get_value();
+            history.last_change.set_value(now);
+
+            if since_last_change > config.coalesce.as_millis() as f64 {
+                let previous = history.current.get_value();
+                history.undo_stack.update(|undo_stack| {
+                    undo_stack.push(previous);
+                    if undo_stack.len() > config.limit {
+                        undo_stack.remove(0);
+                    }
+                });
+                history.redo_stack.update(Vec::clear);
+            }
+            history.current.set_value(values);
+        });
+
+        history
+    }
+
+    pub(crate) fn can_undo(&self) -> bool {
+        self.undo_stack.with(|undo_stack| !undo_stack.is_empty())
+    }
+
+    pub(crate) fn can_redo(&self) -> bool {
+        self.redo_stack.with(|redo_stack| !redo_stack.is_empty())
+    }
+
+    /// The snapshot to show when undoing the last step.
+    pub(crate) fn undo(&self) -> Option<FormFields> {
+        let snapshot = self.undo_stack.try_update(Vec::pop).flatten()?;
+        let current = self.current.get_value();
+        self.redo_stack
+            .update(|redo_stack| redo_stack.push(current));
+        Some(self.apply(snapshot))
+    }
+
+    /// The snapshot to show when redoing the last undone step.
+    pub(crate) fn redo(&self) -> Option<FormFields> {
+        let snapshot = self.redo_stack.try_update(Vec::pop).flatten()?;
+        let current = self.current.get_value();
+        self.undo_stack
+            .update(|undo_stack| undo_stack.push(current));
+        Some(self.apply(snapshot))
+    }
+
+    fn apply(&self, snapshot: FormFields) -> FormFields {
+        self.current.set_value(snapshot.clone());
+        // The next change starts a new step
+        self.last_change.set_value(0.0);
+        self.applying.set_value(true);
+        let applying = self.applying;
+        set_timeout(
+            move || {
+                applying.try_set_value(false);
+            },
+            Duration::ZERO,
+        );
+        snapshot
+    }
+}
@@ -1,117 +1,214 @@
 #![allow(non_snake_case)]
 
 pub mod components;
+mod context;
+mod draft;
 mod error;
+#[cfg(feature = "axum")]
+mod extract;
+mod form_fields;
+mod handle;
+mod history;
 mod name;
 pub mod types;
 
 use bigdecimal::num_bigint::Sign;
+pub use context::*;
+pub use draft::*;
 pub use error::*;
+#[cfg(feature = "axum")]
+pub use extract::*;
+pub use form_fields::*;
+pub use handle::*;
+pub use history::*;
 pub use name::*;
 
 use derive_more::Display;
 pub use formidable_derive::Form;
-use std::{collections::HashMap, fmt::Display, marker::PhantomData, str::FromStr, sync::Arc};
+use std::{any::Any, fmt::Display, marker::PhantomData, str::FromStr, sync::Arc, time::Duration};
 use web_sys::wasm_bindgen::JsCast;
 
-use leptos::{ev::SubmitEvent, prelude::*, server_fn::ServerFn};
+use components::ErrorSummary;
+use leptos::{
+    ev::{KeyboardEvent, SubmitEvent},
+    prelude::*,
+    server_fn::ServerFn,
+};
 
 use std::fmt::Debug;
 
+#[cfg(feature = "regex")]
+pub use regex;
+
+/// The compiled `pattern` of a `#[derive(FormType)]`, which was validated by the derive.
+#[cfg(feature = "regex")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __form_type_pattern {
+    ($pattern:expr) => {{
+        static PATTERN: std::sync::OnceLock<$crate::regex::Regex> = std::sync::OnceLock::new();
+        PATTERN.get_or_init(|| $crate::regex::Regex::new($pattern).expect("Invalid pattern"))
+    }};
+}
+
+#[cfg(not(feature = "regex"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __form_type_pattern {
+    ($pattern:expr) => {
+        compile_error!("The `pattern` attribute of `#[derive(FormType)]` requires the `regex` feature of formidable")
+    };
+}
 pub use strum;
 
-#[derive(Clone, Copy)]
-pub struct FormContext(RwSignal<FormContextInner>);
+#[derive(Clone, Default)]
+pub struct FieldConfiguration {
+    pub label: Option<TextProp>,
+    pub description: Option<TextProp>,
+    pub class: Option<String>,
+    pub colspan: Option<u32>,
+    pub placeholder: Option<String>,
+    pub validation_mode: Option<ValidationMode>,
+    /// Only used by `Option` fields.
+    pub presence: Option<Presence>,
+    /// The minimum number of items of `Vec` fields.
+    pub min_items: Option<usize>,
+    /// The maximum number of items of `Vec` fields.
+    pub max_items: Option<usize>,
+    /// The label of the items of `Vec` fields.
+    pub item_label: Option<ItemLabel>,
+}
 
-pub struct FormContextInner {
-    name: Name,
-    children: HashMap<Name, FormContext>,
-    fields: HashMap<Name, FieldContext>,
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct FormConfiguration {
+    pub section_label: SectionHeading,
+    pub submit_mode: SubmitMode,
+    pub validation_mode: ValidationMode,
+    /// Show an `ErrorSummary` at the top of the form after an invalid form was submitted.
+    pub error_summary: bool,
 }
 
-impl FormContext {
-    pub fn new(name: Name) -> Self {
-        Self(RwSignal::new(FormContextInner {
-            name,
-            children: HashMap::new(),
-            fields: HashMap::new(),
-        }))
+impl FormConfiguration {
+    /// Whether submitting an invalid form reveals its errors instead of the submit button being
+    /// disabled. Forms validating on submit always do, or their errors would never be shown.
+    pub(crate) fn reveals_errors(&self) -> bool {
+        self.submit_mode == SubmitMode::RevealErrors
+            || self.validation_mode == ValidationMode::OnSubmit
     }
+}
 
-    pub fn new_field(&self, name_part: NamePart) -> FieldContext {
-        let new_name = self.0.read_untracked().name.push(name_part);
-
-        let this = FieldContext(RwSignal::new(FieldContextInner {
-            name: new_name,
-            error: RwSignal::new(None),
-            touched: RwSignal::new(false),
-            disabled: RwSignal::new(false),
-            optional: RwSignal::new(false),
-        }));
-
-        let parent = self.0;
-
-        parent.update(|inner: &mut FormContextInner| {
-            inner.fields.insert(new_name, this);
-        });
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SubmitMode {
+    /// The submit button is disabled as long as the form is invalid. Forms with
+    /// [`ValidationMode::OnSubmit`] behave like [`SubmitMode::RevealErrors`] instead.
+    #[default]
+    DisableWhenInvalid,
+    /// The submit button stays enabled. Submitting an invalid form marks all fields as
+    /// touched, which reveals their errors, and focuses the first invalid field.
+    RevealErrors,
+}
 
-        on_cleanup(move || {
-            parent.update(|inner| {
-                inner.fields.remove(&new_name);
-            });
-        });
+/// Decides when a field validates its input and shows the result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ValidationMode {
+    /// Validate on every input.
+    #[default]
+    OnInput,
+    /// Validate when the field loses focus.
+    OnBlur,
+    /// Validate when the form is submitted, then on every input. Configured for a form, the
+    /// submit button stays enabled regardless of the [`SubmitMode`].
+    OnSubmit,
+    /// Validate when the field loses focus for the first time, then on every input.
+    OnBlurThenInput,
+}
 
-        this
+impl ValidationMode {
+    /// The mode of a field, falling back to the mode configured for the form.
+    pub(crate) fn or_configured(mode: Option<ValidationMode>) -> ValidationMode {
+        mode.unwrap_or_else(|| {
+            use_context::<FormConfiguration>()
+                .unwrap_or_default()
+                .validation_mode
+        })
     }
 
-    pub fn new_child(&self, name_part: NamePart) -> FormContext {
-        let new_name = self.0.read_untracked().name.push(name_part);
-
-        let this = Self(RwSignal::new(FormContextInner {
-            name: new_name,
-            children: HashMap::new(),
-            fields: HashMap::new(),
-        }));
+    pub(crate) fn validates_on_input(&self) -> bool {
+        matches!(self, ValidationMode::OnInput)
+    }
 
-        let parent = self.0;
+    pub(crate) fn validates_on_blur(&self) -> bool {
+        !matches!(self, ValidationMode::OnSubmit)
+    }
+}
 
-        parent.update(|inner| {
-            inner.children.insert(new_name, this);
-        });
+/// Decides how an `Option` field lets the user choose between `None` and `Some`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Presence {
+    /// A checkbox that shows the form of the value when checked.
+    #[default]
+    Checkbox,
+    /// The input of the value is always shown and an empty input means `None`.
+    /// Only supported for values rendered as a single input, such as `FormType`s.
+    EmptyIsNone,
+    /// An "Add" button that shows the form of the value, which has a "Remove" button.
+    Button,
+}
 
-        on_cleanup(move || {
-            parent.update(|inner| {
-                inner.children.remove(&new_name);
-            });
-        });
+/// A function labelling an item by its position and value, see [`ItemLabel::Function`].
+pub type ItemLabelFn = Arc<dyn Fn(usize, &dyn Any) -> Option<String> + Send + Sync>;
 
-        this
-    }
+/// Fields whose items can be labelled by a function with the `item_label` attribute.
+pub trait LabelledItems {
+    type Item: 'static;
 }
 
-#[derive(Clone, Copy)]
-pub struct FieldContext(RwSignal<FieldContextInner>);
-
-pub struct FieldContextInner {
-    pub name: Name,
-    pub error: RwSignal<Option<FieldError>>,
-    pub touched: RwSignal<bool>,
-    pub disabled: RwSignal<bool>,
-    pub optional: RwSignal<bool>,
+impl<T: 'static> LabelledItems for Vec<T> {
+    type Item = T;
 }
 
+/// The label of each item of a `Vec` field, set with the `item_label` attribute.
 #[derive(Clone)]
-pub struct FieldConfiguration {
-    pub label: Option<TextProp>,
-    pub description: Option<TextProp>,
-    pub class: Option<String>,
-    pub colspan: Option<u32>,
-    pub placeholder: Option<String>,
+pub enum ItemLabel {
+    /// A template such as `"Stop {index}"`, where `{index}` is the position of the item,
+    /// starting at 1.
+    Template(String),
+    /// A function of the position of the item, starting at 1, and its value. Only called for
+    /// valid items, others are labelled with the default template.
+    Function(ItemLabelFn),
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
-pub struct FormConfiguration {
-    pub section_label: SectionHeading,
+impl ItemLabel {
+    /// Labels the items of a field of type `L`, e.g. `ItemLabel::function::<Vec<Leg>>(...)`.
+    /// The item type of `L` is checked at compile time, so the function is called for every
+    /// valid item of the field.
+    pub fn function<L: LabelledItems>(
+        label: impl Fn(usize, &L::Item) -> String + Send + Sync + 'static,
+    ) -> Self {
+        ItemLabel::Function(Arc::new(move |position, item| {
+            item.downcast_ref::<L::Item>().map(|item| label(position, item))
+        }))
+    }
+
+    /// The label of the item at `index`, or the default label if there is no `ItemLabel`.
+    pub(crate) fn label<T: 'static>(
+        item_label: Option<&ItemLabel>,
+        index: usize,
+        item: Option<&T>,
+    ) -> String {
+        let position = index + 1;
+        let template = match item_label {
+            Some(ItemLabel::Template(template)) => template.clone(),
+            Some(ItemLabel::Function(label)) => {
+                if let Some(label) = item.and_then(|item| label(position, item)) {
+                    return label;
+                }
+                t(FormMessage::ItemLabel)
+            }
+            None => t(FormMessage::ItemLabel),
+        };
+        template.replace("{index}", &position.to_string())
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -146,12 +243,42 @@ pub enum SectionHeading {
 }
 
 pub trait Form: Sized + Send + Sync + 'static {
+    /// Whether the value is entered in a single text input named after the field, whose raw
+    /// value can be set directly, e.g. when pasting into a table.
+    const SINGLE_INPUT: bool = false;
+
     fn view(
         field: FieldConfiguration,
         name: Name,
         value: Option<Self>,
         callback: Option<Callback<Result<Self, FormError>>>,
     ) -> impl IntoView;
+
+    /// Renders the value read-only, e.g. on the review page of a form. Types without a summary
+    /// show their label with the placeholder [`FormMessage::NoSummary`], so existing
+    /// implementations keep compiling.
+    fn view_summary(field: FieldConfiguration, _name: Name, _value: Self) -> impl IntoView {
+        view! {
+            <components::SummaryField
+                label=field.label
+                value=t(FormMessage::NoSummary)
+                class=Some(format!("no-summary{}", field.class.map(|c| format!(" {}", c)).unwrap_or_default()))
+                colspan=field.colspan
+            />
+        }
+    }
+}
+
+/// Re-runs the validation of the form inputs on a value, e.g. on the server after the value
+/// has been deserialized. Returns the same `FormError` the form would produce for this value.
+///
+/// Implemented for all built-in types and generated by `#[derive(Form)]`.
+pub trait Validate {
+    /// Accepts every value by default, so types implementing `Form` by hand only need an empty
+    /// `impl Validate for MyType {}`.
+    fn validate(&self, _name: Name) -> Result<(), FormError> {
+        Ok(())
+    }
 }
 
 #[component]
@@ -162,23 +289,37 @@ pub fn FormidableCallback<T>(
     #[prop(into)] name: Name,
     #[prop(optional)] value: Option<T>,
     #[prop(into)] callback: Callback<T>,
+    #[prop(optional)] handle: Option<FormHandle<T>>,
+    /// Persist the partial state of the form, so it survives reloads.
+    #[prop(into, optional)]
+    persist: Option<Persist>,
+    /// Allow undoing changes of the form via its `FormHandle`.
+    #[prop(optional)]
+    history: Option<UndoHistory>,
 ) -> impl IntoView
 where
     T: Form + Clone,
 {
-    let curr_value = RwSignal::new(None);
-    let form_callback = Callback::new(
-        move |form_result: Result<T, FormError>| match &form_result {
-            Ok(v) => {
-                curr_value.set(Some(v.clone()));
-            }
-            Err(_) => {
-                curr_value.set(None);
-            }
-        },
-    );
+    let handle = handle.unwrap_or_default();
+    let form_context = FormContext::new(name.clone());
+    let form_callback = Callback::new(move |form_result: Result<T, FormError>| {
+        handle.set_result(form_result);
+    });
+
+    let submit = Callback::new(move |()| {
+        form_context.submitted().set(true);
+        if let Some(value) = untrack(|| handle.value()) {
+            callback.run(value);
+            handle.clear_draft();
+        } else {
+            handle.touch_all();
+            form_context.focus_first_error();
+        }
+    });
 
-    let submit_disabled = Signal::derive(move || curr_value.get().is_none());
+    let submit_disabled = Signal::derive(move || {
+        !form_configuration.reveals_errors() && !handle.is_valid()
+    });
 
     let on_submit = {
         move |ev: SubmitEvent| {
@@ -188,23 +329,41 @@ where
 
             ev.prevent_default();
 
-            if let Some(value) = curr_value.get_untracked() {
-                callback.run(value.clone());
-            }
+            submit.run(());
         }
     };
 
+    handle.attach(value, form_context, Some(submit));
+
     provide_context(form_configuration);
+    provide_context(form_context);
+    provide_context(handle);
+
+    provide_draft(handle, form_context, persist, history);
+    let on_keydown = move |ev: KeyboardEvent| {
+        if history.is_some_and(|history| history.has_keyboard_shortcuts()) {
+            handle_history_shortcut(handle, ev);
+        }
+    };
+
+    let field = FieldConfiguration {
+        label: Some(label),
+        description,
+        ..Default::default()
+    };
 
     view! {
-        <form on:submit=on_submit>
-            {T::view(FieldConfiguration {
-                label: Some(label),
-                description,
-                class: None,
-                colspan: None,
-                placeholder: None,
-            }, name, value, Some(form_callback)) }
+        <form
+            on:submit=on_submit
+            on:keydown=on_keydown
+            novalidate={form_configuration.reveals_errors()}
+        >
+            { form_configuration.error_summary.then(|| view! { <ErrorSummary /> }) }
+            { move || {
+                let value = handle.rendered();
+                let field = field.clone();
+                untrack(|| T::view(field, name.clone(), value, Some(form_callback)))
+            }}
             <button type="submit" disabled=submit_disabled>{t(FormMessage::SubmitButton)}</button>
         </form>
     }
@@ -217,30 +376,43 @@ pub fn FormidableRwSignal<T>(
     #[prop(into, optional)] form_configuration: FormConfiguration,
     #[prop(into)] name: Name,
     #[prop(into)] value: RwSignal<T>,
+    #[prop(optional)] handle: Option<FormHandle<T>>,
+    /// Persist the partial state of the form, so it survives reloads. As the form has no
+    /// submit button, the draft is kept until it expires.
+    #[prop(into, optional)]
+    persist: Option<Persist>,
 ) -> impl IntoView
 where
     T: Form + Clone,
 {
+    let handle = handle.unwrap_or_default();
+    let form_context = FormContext::new(name.clone());
+
+    handle.attach(Some(value.get_untracked()), form_context, None);
+
     provide_context(form_configuration);
+    provide_context(form_context);
+    provide_context(handle);
+    provide_draft(handle, form_context, persist, None);
 
     let callback = Callback::new(move |form_result: Result<T, FormError>| {
-        if let Ok(v) = form_result {
-            value.set(v);
+        if let Ok(v) = &form_result {
+            value.set(v.clone());
         }
+        handle.set_result(form_result);
     });
 
-    T::view(
-        FieldConfiguration {
-            label: Some(label),
-            description,
-            class: None,
-            colspan: None,
-            placeholder: None,
-        },
-        name,
-        Some(value.get_untracked()),
-        Some(callback),
-    )
+    let field = FieldConfiguration {
+        label: Some(label),
+        description,
+        ..Default::default()
+    };
+
+    move || {
+        let value = handle.rendered();
+        let field = field.clone();
+        untrack(|| T::view(field, name.clone(), value, Some(callback)))
+    }
 }
 
 #[component]
@@ -251,6 +423,21 @@ pub fn FormidableServerAction<F, T>(
     #[prop(into)] name: Name,
     #[prop(optional)] value: Option<T>,
     #[prop(optional)] callback: Option<Callback<F::Output, ()>>,
+    #[prop(optional)] handle: Option<FormHandle<T>>,
+    /// The URL the form is posted to if it is submitted before the page is hydrated, e.g. a
+    /// route using `Formidable<T>`. Without it, the form can only be submitted once hydrated.
+    #[prop(into, optional)]
+    action: Option<String>,
+    /// The encoding of the form if it is submitted before the page is hydrated. Forms with
+    /// `File` fields need `multipart/form-data`, handled by an `action` using `Formidable<T>`.
+    #[prop(into, optional)]
+    enctype: Option<String>,
+    /// Persist the partial state of the form, so it survives reloads.
+    #[prop(into, optional)]
+    persist: Option<Persist>,
+    /// Allow undoing changes of the form via its `FormHandle`.
+    #[prop(optional)]
+    history: Option<UndoHistory>,
     #[prop(optional)] _phantom: PhantomData<F>,
 ) -> impl IntoView
 where
@@ -259,19 +446,35 @@ where
     F::Output: Clone + Send + Sync + 'static,
     F::Error: Clone + Send + Sync + Display + 'static,
 {
-    provide_context(form_configuration);
+    let handle = handle.unwrap_or_default();
+    let form_context = FormContext::new(name.clone());
 
-    let submit = ServerAction::<F>::new();
-    let curr_value = RwSignal::new(value.as_ref().map(|v| Ok(v.clone())));
+    let submit_action = ServerAction::<F>::new();
     let form_callback = Callback::new(move |form_result: Result<T, FormError>| {
-        curr_value.set(Some(form_result));
+        handle.set_result(form_result);
     });
+    // Without WASM the browser posts the form to the `action` itself, so the button must stay
+    // enabled if there is one
+    let hydrated = RwSignal::new(false);
+    Effect::new(move || hydrated.set(true));
+    let has_action = action.is_some();
     let submit_disabled = Signal::derive(move || {
-        curr_value
-            .get()
-            .and_then(|v: Result<T, FormError>| v.ok())
-            .is_none()
-            || submit.pending().get()
+        if hydrated.get() {
+            (!form_configuration.reveals_errors() && !handle.is_valid())
+                || submit_action.pending().get()
+        } else {
+            !has_action
+        }
+    });
+
+    let submit = Callback::new(move |()| {
+        form_context.submitted().set(true);
+        if let Some(value) = untrack(|| handle.value()) {
+            submit_action.dispatch(value.into());
+        } else {
+            handle.touch_all();
+            form_context.focus_first_error();
+        }
     });
 
     let on_submit = {
@@ -282,37 +485,67 @@ where
 
             ev.prevent_default();
 
-            if let Some(value) = curr_value.get_untracked().and_then(|v| v.ok()) {
-                submit.dispatch(value.into());
-            }
+            submit.run(());
         }
     };
 
-    if let Some(callback) = callback {
-        Effect::new(move || {
-            if let Some(res) = submit.value().get() {
-                callback.run(res.unwrap());
+    handle.attach(value, form_context, Some(submit));
+
+    provide_context(form_configuration);
+    provide_context(form_context);
+    provide_context(handle);
+
+    provide_draft(handle, form_context, persist, history);
+    let on_keydown = move |ev: KeyboardEvent| {
+        if history.is_some_and(|history| history.has_keyboard_shortcuts()) {
+            handle_history_shortcut(handle, ev);
+        }
+    };
+
+    Effect::new(move || {
+        if let Some(Ok(res)) = submit_action.value().get() {
+            handle.clear_draft();
+            if let Some(callback) = callback {
+                callback.run(res);
             }
-        });
-    }
+        }
+    });
+
+    let server_errors = report_server_errors(submit_action.value(), form_context);
+
+    let field = FieldConfiguration {
+        label: Some(label),
+        description,
+        ..Default::default()
+    };
 
     view! {
-        <form on:submit=on_submit>
-            {T::view(FieldConfiguration {
-                label: Some(label),
-                description,
-                class: None,
-                colspan: None,
-                placeholder: None,
-            }, name, value, Some(form_callback)) }
+        <form
+            method=has_action.then_some("post")
+            action=action
+            enctype=enctype
+            on:submit=on_submit
+            on:keydown=on_keydown
+            novalidate={form_configuration.reveals_errors()}
+        >
+            { form_configuration.error_summary.then(|| view! { <ErrorSummary /> }) }
+            { move || {
+                let value = handle.rendered();
+                let field = field.clone();
+                untrack(|| T::view(field, name.clone(), value, Some(form_callback)))
+            }}
             <button type="submit" disabled=submit_disabled>{t(FormMessage::SubmitButton)}</button>
             { move ||
-                if submit.pending().get() {
+                if submit_action.pending().get() {
                     Some(view! { <p class="message info-message">{t(FormMessage::SubmitPendingMessage)}</p> }.into_any())
                 } else {
-                    submit.value().get().map(|res| match res {
+                    submit_action.value().get().map(|res| match res {
                         Ok(_) => view! { <p class="message success-message">{t(FormMessage::SubmitSuccessMessage)}</p> }.into_any(),
-                        Err(_err) => view! { <p class="message error-message">{t(FormMessage::SubmitErrorMessage)}</p> }.into_any(),
+                        // Listed in the error summary instead, if the form has one
+                        Err(_err) => view! {
+                            <p class="message error-message">{t(FormMessage::SubmitErrorMessage)}</p>
+                            { (!form_configuration.error_summary).then(|| view! { <ServerErrors errors=server_errors /> }) }
+                        }.into_any(),
                     })
                 }
 
@@ -321,6 +554,252 @@ where
     }
 }
 
+/// Provides the draft recording the raw values of the fields of a form. Besides persisting
+/// and undoing changes, it is used to render rearranged items and pasted table rows again
+/// with their raw values, so every form has one.
+fn provide_draft<T: Send + Sync + 'static>(
+    handle: FormHandle<T>,
+    form_context: FormContext,
+    persist: Option<Persist>,
+    history: Option<UndoHistory>,
+) {
+    let draft = Draft::new(persist, form_context);
+    provide_context(draft);
+    handle.attach_draft(draft);
+    if let Some(history) = history {
+        handle.attach_history(History::new(history, draft, form_context));
+    }
+}
+
+/// Undo with Ctrl+Z, redo with Ctrl+Shift+Z or Ctrl+Y, using Cmd instead of Ctrl on macOS.
+fn handle_history_shortcut<T: Send + Sync + 'static>(handle: FormHandle<T>, ev: KeyboardEvent) {
+    if !(ev.ctrl_key() || ev.meta_key()) || ev.alt_key() {
+        return;
+    }
+
+    let applied = match ev.key().to_lowercase().as_str() {
+        "z" if ev.shift_key() => handle.redo(),
+        "z" => handle.undo(),
+        "y" => handle.redo(),
+        _ => return,
+    };
+    // Without a change of the form, the browser undoes the change of the focused input itself
+    if applied {
+        ev.prevent_default();
+    }
+}
+
+/// A form without a submit button that saves its value with the server function `F`
+/// whenever it is valid and hasn't changed for `debounce`, e.g. for settings pages.
+///
+/// Values equal to the last saved one aren't sent again, and changes made while a save is in
+/// flight are saved once it has finished and they haven't changed for `debounce`. Failed saves
+/// are retried the same way, unless the server rejected the value with field errors.
+#[component]
+pub fn FormidableAutosave<F, T>(
+    #[prop(into)] label: TextProp,
+    #[prop(into, optional)] description: Option<TextProp>,
+    #[prop(into, optional)] form_configuration: FormConfiguration,
+    #[prop(into)] name: Name,
+    #[prop(optional)] value: Option<T>,
+    #[prop(optional)] callback: Option<Callback<F::Output, ()>>,
+    #[prop(optional)] handle: Option<FormHandle<T>>,
+    /// How long the value has to stay unchanged before it is saved.
+    #[prop(default = Duration::from_secs(1))]
+    debounce: Duration,
+    #[prop(optional)] _phantom: PhantomData<F>,
+) -> impl IntoView
+where
+    T: Form + Clone + PartialEq + Debug + Send + Sync + 'static,
+    F: ServerFn + Clone + From<T> + Send + Sync + 'static,
+    F::Output: Clone + Send + Sync + 'static,
+    F::Error: Clone + Send + Sync + Display + 'static,
+{
+    let handle = handle.unwrap_or_default();
+    let form_context = FormContext::new(name.clone());
+
+    let save_action = ServerAction::<F>::new();
+    let form_callback = Callback::new(move |form_result: Result<T, FormError>| {
+        handle.set_result(form_result);
+    });
+
+    // The value that was saved last, which starts out as the value the form was rendered with
+    let last_saved = StoredValue::new(value.clone());
+    // The value of the save in flight, and the last value the server rejected as invalid
+    let in_flight = StoredValue::new(None::<T>);
+    let rejected = StoredValue::new(None::<T>);
+    let timeout = StoredValue::new(None::<TimeoutHandle>);
+
+    let save = Callback::new(move |()| {
+        if let Some(timeout) = timeout.get_value() {
+            timeout.clear();
+        }
+        // Saved once the request in flight has finished
+        if save_action.pending().get_untracked() {
+            return;
+        }
+        let Some(value) = untrack(|| handle.value()) else {
+            return;
+        };
+        if !untrack(|| form_context.is_dirty())
+            || last_saved.with_value(|last_saved| last_saved.as_ref() == Some(&value))
+            || rejected.with_value(|rejected| rejected.as_ref() == Some(&value))
+        {
+            return;
+        }
+        in_flight.set_value(Some(value.clone()));
+        save_action.dispatch(value.into());
+    });
+
+    let schedule = move || {
+        if let Some(timeout) = timeout.get_value() {
+            timeout.clear();
+        }
+        if handle.value().is_some() {
+            timeout.set_value(set_timeout_with_handle(move || save.run(()), debounce).ok());
+        }
+    };
+
+    Effect::new(move |_| schedule());
+
+    // Changes made while a save was in flight, and values whose save failed, are saved once
+    // they haven't changed for `debounce`
+    Effect::new(move |_| {
+        if !save_action.pending().get() {
+            untrack(schedule);
+        }
+    });
+
+    let on_submit = move |ev: SubmitEvent| {
+        ev.prevent_default();
+        save.run(());
+    };
+
+    handle.attach(value, form_context, Some(save));
+
+    provide_context(form_configuration);
+    provide_context(form_context);
+    provide_context(handle);
+    provide_draft(handle, form_context, None, None);
+
+    Effect::new(move || match save_action.value().get() {
+        Some(Ok(res)) => {
+            last_saved.set_value(in_flight.get_value());
+            if let Some(callback) = callback {
+                callback.run(res);
+            }
+        }
+        // Values rejected by the server aren't sent again until they change, other failed
+        // saves are retried
+        Some(Err(err)) => {
+            let err = &err as &dyn Any;
+            if err.is::<ServerFieldErrors>() || err.is::<FormError>() {
+                rejected.set_value(in_flight.get_value());
+            }
+        }
+        None => {}
+    });
+
+    let server_errors = report_server_errors(save_action.value(), form_context);
+
+    let field = FieldConfiguration {
+        label: Some(label),
+        description,
+        ..Default::default()
+    };
+
+    view! {
+        <form on:submit=on_submit novalidate=true>
+            { move || {
+                let value = handle.rendered();
+                let field = field.clone();
+                untrack(|| T::view(field, name.clone(), value, Some(form_callback)))
+            }}
+            <div class="autosave-status" role="status" aria-live="polite">
+                { move ||
+                    if save_action.pending().get() {
+                        Some(view! { <p class="message info-message">{t(FormMessage::AutosavePendingMessage)}</p> }.into_any())
+                    } else {
+                        save_action.value().get().map(|res| match res {
+                            Ok(_) => view! { <p class="message success-message">{t(FormMessage::AutosaveSuccessMessage)}</p> }.into_any(),
+                            Err(_err) => view! {
+                                <p class="message error-message">{t(FormMessage::AutosaveErrorMessage)}</p>
+                                <ServerErrors errors=server_errors />
+                            }.into_any(),
+                        })
+                    }
+                }
+            </div>
+        </form>
+    }
+}
+
+/// Shows the errors of a failed server function call at the fields they belong to, and
+/// returns the errors that don't belong to any field. Errors other than `ServerFieldErrors`
+/// and `FormError`, such as a `ServerFnError`, are shown with their message.
+fn report_server_errors<O, E>(
+    result: impl With<Value = Option<Result<O, E>>> + Copy + Send + Sync + 'static,
+    form_context: FormContext,
+) -> RwSignal<Vec<String>>
+where
+    O: Send + Sync + 'static,
+    E: Display + Send + Sync + 'static,
+{
+    Effect::new(move || {
+        let errors = result.with(|res| match res {
+            Some(Err(err)) => {
+                let message = err.to_string();
+                let err = err as &dyn Any;
+                err.downcast_ref::<ServerFieldErrors>()
+                    .cloned()
+                    .or_else(|| err.downcast_ref::<FormError>().cloned().map(Into::into))
+                    .unwrap_or_else(|| ServerFieldErrors::new().with_form_error(message))
+            }
+            _ => ServerFieldErrors::new(),
+        });
+        form_context.set_server_errors(&errors);
+    });
+    form_context.server_errors()
+}
+
+#[component]
+fn ServerErrors(errors: RwSignal<Vec<String>>) -> impl IntoView {
+    move || {
+        let errors = errors.get();
+        (!errors.is_empty()).then(|| {
+            view! {
+                <ul class="message error-message server-errors">
+                    { errors.into_iter().map(|error| view! { <li>{error}</li> }).collect_view() }
+                </ul>
+            }
+        })
+    }
+}
+
+#[component]
+pub fn FormidableSummary<T>(
+    #[prop(into)] label: TextProp,
+    #[prop(into, optional)] description: Option<TextProp>,
+    #[prop(into, optional)] form_configuration: FormConfiguration,
+    #[prop(into)] name: Name,
+    value: T,
+) -> impl IntoView
+where
+    T: Form,
+{
+    provide_context(form_configuration);
+
+    T::view_summary(
+        FieldConfiguration {
+            label: Some(label),
+            description,
+            ..Default::default()
+        },
+        name,
+        value,
+    )
+}
+
 #[derive(Clone)]
 pub struct Translation<T>(Arc<dyn Fn(T) -> String>);
 
@@ -356,10 +835,50 @@ pub enum FormMessage {
     AddButton,
     #[display("Remove")]
     RemoveButton,
+    #[display("Move up")]
+    MoveUpButton,
+    #[display("Move down")]
+    MoveDownButton,
+    #[display("Duplicate")]
+    DuplicateButton,
+    #[display("Insert above")]
+    InsertAboveButton,
+    #[display("Item {{index}}")]
+    ItemLabel,
+    #[display("Collapse")]
+    CollapseButton,
+    #[display("Expand")]
+    ExpandButton,
+    #[display("Contains errors")]
+    ItemErrorIndicator,
     #[display("Submitting ...")]
     SubmitPendingMessage,
+    #[display("Checking ...")]
+    ValidationPendingMessage,
+    #[display("Saving ...")]
+    AutosavePendingMessage,
     #[display("Form submitted successfully")]
     SubmitSuccessMessage,
     #[display("Error submitting form")]
     SubmitErrorMessage,
+    #[display("Review")]
+    ReviewHeading,
+    #[display("Yes")]
+    Yes,
+    #[display("No")]
+    No,
+    #[display("Not provided")]
+    NotProvided,
+    #[display("No summary available")]
+    NoSummary,
+    #[display("Saved")]
+    AutosaveSuccessMessage,
+    #[display("Saving failed")]
+    AutosaveErrorMessage,
+    #[display("Key")]
+    MapKeyLabel,
+    #[display("Value")]
+    MapValueLabel,
+    #[display("There is a problem")]
+    ErrorSummaryHeading,
 }
@@ -1,50 +1,132 @@
-use std::fmt::{Debug, Display};
+use std::{
+    fmt::{Debug, Display},
+    str::FromStr,
+    sync::Arc,
+};
 
-#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
-pub struct Name([Option<NamePart>; 16]);
+use thiserror::Error;
+
+/// The name of a form field, e.g. `user_form[order][0][name]`.
+///
+/// The parts of a name are reference-counted, so names are cheap to clone and can be nested
+/// to any depth. Names parsed at runtime are freed once they are no longer used.
+#[derive(Clone, PartialEq, Eq, Hash, Default)]
+pub struct Name(Arc<[NamePart]>);
 
 impl Name {
     pub fn len(&self) -> usize {
-        self.0.iter().take_while(|part| part.is_some()).count()
+        self.0.len()
     }
 
     pub fn is_empty(&self) -> bool {
-        self.len() == 0
+        self.0.is_empty()
     }
 
-    pub fn push(mut self, part: NamePart) -> Self {
-        let len = self.len();
-        if len < self.0.len() {
-            self.0[len] = Some(part);
-        } else {
-            panic!("Name parts exceed maximum length");
-        }
-        self
+    pub fn push(&self, part: NamePart) -> Self {
+        Name(self.0.iter().cloned().chain([part]).collect())
     }
 
-    pub fn push_index(self, index: usize) -> Self {
+    pub fn push_index(&self, index: usize) -> Self {
         self.push(NamePart::Index(index))
     }
 
-    pub fn push_key(self, key: &'static str) -> Self {
-        self.push(NamePart::Key(key))
+    pub fn push_key(&self, key: &str) -> Self {
+        self.push(NamePart::Key(key.into()))
+    }
+
+    /// The name without its last part, or `None` for the empty name.
+    pub fn parent(&self) -> Option<Name> {
+        let (_, parent) = self.0.split_last()?;
+        Some(Name(parent.into()))
+    }
+
+    /// The last part of the name, e.g. `name` for `user_form[order][0][name]`.
+    pub fn last(&self) -> Option<NamePart> {
+        self.0.last().cloned()
+    }
+
+    /// The parts of the name, from the outermost to the innermost.
+    pub fn iter(&self) -> std::slice::Iter<'_, NamePart> {
+        self.0.iter()
+    }
+
+    /// Whether `other` is this name or one of its ancestors.
+    pub fn contains(&self, other: &Name) -> bool {
+        self.0.starts_with(&other.0)
     }
 
-    pub fn contains(self, other: Name) -> bool {
-        let self_len = self.len();
-        let other_len = other.len();
+    /// Parses a name such as `user_form[order][0][name]`, rejecting malformed brackets.
+    ///
+    /// Unlike the lenient `From<&str>` conversion, which skips anything it doesn't understand.
+    pub fn parse(value: &str) -> Result<Self, NameError> {
+        let mut parts = Vec::new();
+        let mut chars = value.char_indices().peekable();
 
-        if other_len > self_len {
-            return false;
+        // The first part isn't enclosed in brackets
+        let mut first = String::new();
+        while let Some(&(position, c)) = chars.peek() {
+            match c {
+                '[' => break,
+                ']' => {
+                    return Err(NameError::UnexpectedCharacter {
+                        character: c,
+                        position,
+                    })
+                }
+                _ => first.push(c),
+            }
+            chars.next();
+        }
+        if !first.is_empty() {
+            parts.push(NamePart::Key(first.into()));
         }
 
-        for i in 0..other_len {
-            if self.0[i] != other.0[i] {
-                return false;
+        while let Some((start, c)) = chars.next() {
+            if c != '[' {
+                return Err(NameError::UnexpectedCharacter {
+                    character: c,
+                    position: start,
+                });
+            }
+
+            let mut part = String::new();
+            loop {
+                match chars.next() {
+                    Some((_, ']')) => break,
+                    Some((position, '[')) => {
+                        return Err(NameError::UnexpectedCharacter {
+                            character: '[',
+                            position,
+                        })
+                    }
+                    Some((_, c)) => part.push(c),
+                    None => return Err(NameError::UnclosedBracket { position: start }),
+                }
+            }
+            if part.is_empty() {
+                return Err(NameError::EmptyPart { position: start });
             }
+            parts.push(NamePart::parse(&part));
         }
 
-        true
+        Ok(Name(parts.into()))
+    }
+}
+
+impl<'a> IntoIterator for &'a Name {
+    type Item = &'a NamePart;
+    type IntoIter = std::slice::Iter<'a, NamePart>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl FromStr for Name {
+    type Err = NameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Name::parse(s)
     }
 }
 
@@ -53,32 +135,14 @@ where
     T: AsRef<str>,
 {
     fn from(value: T) -> Self {
-        let mut name = Name::default();
-        let mut curr = String::new();
-
-        for c in value.as_ref().chars() {
-            if c == '[' || c == ']' {
-                if !curr.is_empty() {
-                    if let Ok(index) = curr.parse::<usize>() {
-                        name = name.push_index(index);
-                    } else {
-                        name = name.push_key(Box::leak(curr.clone().into_boxed_str()));
-                    }
-                    curr.clear();
-                }
-            } else {
-                curr.push(c);
-            }
-        }
-        if !curr.is_empty() {
-            if let Ok(index) = curr.parse::<usize>() {
-                name = name.push_index(index);
-            } else {
-                name = name.push_key(Box::leak(curr.clone().into_boxed_str()));
-            }
-        }
-
-        name
+        Name(
+            value
+                .as_ref()
+                .split(['[', ']'])
+                .filter(|part| !part.is_empty())
+                .map(NamePart::parse)
+                .collect(),
+        )
     }
 }
 
@@ -90,25 +154,178 @@ impl Debug for Name {
 
 impl Display for Name {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let name = self
-            .0
-            .iter()
-            .filter_map(|&o| o)
-            .enumerate()
-            .map(|(i, part)| match part {
-                NamePart::Index(i) => format!("[{}]", i),
-                NamePart::Key(k) if i == 0 => k.to_string(),
-                NamePart::Key(k) => format!("[{}]", k),
-            })
-            .collect::<Vec<_>>()
-            .join("");
-
-        write!(f, "{}", name)
+        for (i, part) in self.iter().enumerate() {
+            match part {
+                NamePart::Index(index) => write!(f, "[{}]", index)?,
+                NamePart::Key(key) if i == 0 => write!(f, "{}", key)?,
+                NamePart::Key(key) => write!(f, "[{}]", key)?,
+            }
+        }
+        Ok(())
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub enum NamePart {
     Index(usize),
-    Key(&'static str),
+    Key(Arc<str>),
+}
+
+impl NamePart {
+    /// Parts consisting of digits only are indices.
+    fn parse(part: &str) -> Self {
+        match part.parse::<usize>() {
+            Ok(index) => NamePart::Index(index),
+            Err(_) => NamePart::Key(part.into()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Error, PartialEq, Eq, Hash)]
+pub enum NameError {
+    #[error("Unexpected '{character}' at position {position}")]
+    UnexpectedCharacter { character: char, position: usize },
+    #[error("Missing ']' for the '[' at position {position}")]
+    UnclosedBracket { position: usize },
+    #[error("Empty brackets at position {position}")]
+    EmptyPart { position: usize },
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Name {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Name {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Name::parse(&name).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_splits_keys_and_indices() {
+        let name = Name::parse("user_form[order][0][name]").unwrap();
+        assert_eq!(
+            name.iter().cloned().collect::<Vec<_>>(),
+            [
+                NamePart::Key("user_form".into()),
+                NamePart::Key("order".into()),
+                NamePart::Index(0),
+                NamePart::Key("name".into()),
+            ]
+        );
+        assert_eq!(
+            name,
+            Name::from("user_form")
+                .push_key("order")
+                .push_index(0)
+                .push_key("name")
+        );
+        assert_eq!(Name::parse(""), Ok(Name::default()));
+    }
+
+    #[test]
+    fn parse_rejects_unclosed_brackets() {
+        assert_eq!(
+            Name::parse("form[order"),
+            Err(NameError::UnclosedBracket { position: 4 })
+        );
+        assert_eq!(
+            Name::parse("form[order][0"),
+            Err(NameError::UnclosedBracket { position: 11 })
+        );
+        assert_eq!(
+            Name::parse("form[a[b]]"),
+            Err(NameError::UnexpectedCharacter {
+                character: '[',
+                position: 6
+            })
+        );
+    }
+
+    #[test]
+    fn parse_rejects_empty_brackets() {
+        assert_eq!(
+            Name::parse("form[]"),
+            Err(NameError::EmptyPart { position: 4 })
+        );
+        assert_eq!(
+            Name::parse("form[a][]"),
+            Err(NameError::EmptyPart { position: 7 })
+        );
+    }
+
+    #[test]
+    fn parse_rejects_unexpected_characters() {
+        assert_eq!(
+            Name::parse("form]"),
+            Err(NameError::UnexpectedCharacter {
+                character: ']',
+                position: 4
+            })
+        );
+        assert_eq!(
+            Name::parse("form[a]b"),
+            Err(NameError::UnexpectedCharacter {
+                character: 'b',
+                position: 7
+            })
+        );
+    }
+
+    #[test]
+    fn errors_name_the_position() {
+        assert_eq!(
+            NameError::UnclosedBracket { position: 4 }.to_string(),
+            "Missing ']' for the '[' at position 4"
+        );
+        assert_eq!(
+            NameError::EmptyPart { position: 4 }.to_string(),
+            "Empty brackets at position 4"
+        );
+    }
+
+    #[test]
+    fn names_are_not_limited_in_depth() {
+        let mut name = Name::from("root");
+        for index in 0..20 {
+            name = name.push_key("children").push_index(index);
+        }
+        assert_eq!(name.len(), 41);
+        assert_eq!(name.last(), Some(NamePart::Index(19)));
+        assert_eq!(Name::parse(&name.to_string()), Ok(name));
+    }
+
+    #[test]
+    fn display_round_trips() {
+        for name in [
+            "form",
+            "form[order][0][name]",
+            "form[headers][Content-Type]",
+        ] {
+            assert_eq!(Name::parse(name).unwrap().to_string(), name);
+            assert_eq!(Name::from(name).to_string(), name);
+        }
+        let name = Name::from("form").push_key("tags").push_index(2);
+        assert_eq!(Name::parse(&name.to_string()), Ok(name));
+    }
+
+    #[test]
+    fn parent_and_contains() {
+        let name = Name::from("form[order][0]");
+        assert_eq!(name.parent(), Some(Name::from("form[order]")));
+        assert_eq!(Name::default().parent(), None);
+        assert!(name.contains(&Name::from("form[order]")));
+        assert!(name.contains(&name));
+        assert!(!Name::from("form[order]").contains(&name));
+        assert!(!name.contains(&Name::from("form[orders]")));
+    }
 }
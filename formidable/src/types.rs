@@ -3,14 +3,23 @@
 mod accept;
 #[cfg(feature = "bigdecimal")]
 mod bigdecimal;
+#[cfg(feature = "bounded-vec")]
+mod bounded_vec;
 #[cfg(feature = "color")]
 mod color;
+#[cfg(feature = "deranged")]
+mod deranged;
 mod email;
 #[cfg(feature = "file")]
 mod file;
+mod map;
 mod non_empty_string;
 mod option;
+#[cfg(feature = "regex")]
+mod pattern;
+mod selection;
 mod std_types;
+mod table;
 mod tel;
 #[cfg(feature = "time")]
 mod time;
@@ -22,20 +31,30 @@ pub use accept::*;
 pub use bigdecimal::*;
 #[cfg(feature = "color")]
 pub use color::*;
+#[cfg(feature = "deranged")]
+pub use deranged::*;
 pub use email::*;
 #[cfg(feature = "file")]
 pub use file::*;
+pub use map::*;
 pub use non_empty_string::*;
 pub use option::*;
+#[cfg(feature = "regex")]
+pub use pattern::*;
+pub use selection::*;
+pub use std_types::{validate_item_count, ItemCountError};
+pub use table::*;
 pub use tel::*;
 #[cfg(feature = "time")]
 pub use time::*;
 #[cfg(feature = "url")]
 pub use url::*;
 
+pub use formidable_derive::FormType;
+
 use crate::{
-    components::{Input, InputType},
-    FieldError, Form, FormError, Name,
+    components::{Input, InputType, SummaryField},
+    FieldError, Form, FormError, FormFields, FromFormFields, Name, Validate,
 };
 use leptos::prelude::*;
 use std::fmt::Display;
@@ -46,6 +65,8 @@ where
     T: FormType,
     <T as FromStr>::Err: Clone + Display + Send + Sync + 'static,
 {
+    const SINGLE_INPUT: bool = true;
+
     fn view(
         field: crate::FieldConfiguration,
         name: Name,
@@ -56,7 +77,8 @@ where
             <Input<T>
                 label=field.label.expect("No label provided")
                 description=field.description
-                name=name
+                name=name.clone()
+                validation_mode=field.validation_mode
                 value=value
                 callback={callback.map(|callback| Callback::new(move |v: Result<Self, FieldError>| {
                     callback.run(v.map_err(FormError::from));
@@ -72,6 +94,7 @@ where
                 required=T::REQUIRED
                 minlength=T::MIN_LENGTH
                 maxlength=T::MAX_LENGTH
+                pattern=T::PATTERN
                 min=T::MIN
                 max=T::MAX
                 step=T::STEP
@@ -80,6 +103,62 @@ where
             />
         }
     }
+
+    fn view_summary(field: crate::FieldConfiguration, _name: Name, value: Self) -> impl IntoView {
+        view! {
+            <SummaryField
+                label=field.label
+                value=value.to_string()
+                class=field.class
+                colspan=field.colspan
+            />
+        }
+    }
+}
+
+impl<T> Validate for T
+where
+    T: FormType,
+    <T as FromStr>::Err: Display + Send + Sync + 'static,
+{
+    fn validate(&self, name: Name) -> Result<(), FormError> {
+        validate_from_str(self, name)
+    }
+}
+
+impl<T> FromFormFields for T
+where
+    T: FormType,
+    <T as FromStr>::Err: Display + Send + Sync + 'static,
+{
+    fn from_form_fields(fields: &FormFields, name: Name) -> Result<Self, FormError> {
+        parse_form_field(fields, name)
+    }
+}
+
+/// Parses a submitted field the same way the input does, treating a missing field as empty.
+pub(crate) fn parse_form_field<T>(fields: &FormFields, name: Name) -> Result<T, FormError>
+where
+    T: FromStr,
+    <T as FromStr>::Err: Display + Send + Sync + 'static,
+{
+    let raw_value = fields.get(&name).unwrap_or_default();
+    raw_value
+        .parse::<T>()
+        .map_err(|err| FieldError::new_string(name, err, raw_value.to_owned()).into())
+}
+
+/// Validates a value by parsing its string representation the same way the input does.
+pub(crate) fn validate_from_str<T>(value: &T, name: Name) -> Result<(), FormError>
+where
+    T: Display + FromStr,
+    <T as FromStr>::Err: Display + Send + Sync + 'static,
+{
+    let raw_value = value.to_string();
+    raw_value
+        .parse::<T>()
+        .map(|_| ())
+        .map_err(|err| FieldError::new_string(name, err, raw_value).into())
 }
 
 pub trait FormType: Clone + Display + FromStr + Send + Sync + 'static {
@@ -87,6 +166,7 @@ pub trait FormType: Clone + Display + FromStr + Send + Sync + 'static {
     const REQUIRED: Option<bool> = None;
     const MIN_LENGTH: Option<usize> = None;
     const MAX_LENGTH: Option<usize> = None;
+    const PATTERN: Option<&'static str> = None;
     const MIN: Option<Self> = None;
     const MAX: Option<Self> = None;
     const STEP: Option<Self> = None;
@@ -5,10 +5,14 @@ use leptos::prelude::*;
 use derive_more::{Deref, Display, Into};
 use thiserror::Error;
 
-use crate::{components::Checkbox, FieldError, Form, FormError, Name};
+use crate::{
+    components::{Checkbox, SummaryField},
+    t, FieldError, Form, FormError, FormFields, FormMessage, FromFormFields, Name, Validate,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Display, Into, Deref)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "bool"))]
 pub struct Accept(bool);
 
 #[derive(Debug, Clone, Copy, Error, PartialEq, Eq, Hash)]
@@ -29,6 +33,21 @@ impl TryFrom<bool> for Accept {
     }
 }
 
+impl Validate for Accept {
+    fn validate(&self, name: Name) -> Result<(), FormError> {
+        Accept::try_from(self.0)
+            .map(|_| ())
+            .map_err(|err| FieldError::new(name, err).into())
+    }
+}
+
+impl FromFormFields for Accept {
+    fn from_form_fields(fields: &FormFields, name: Name) -> Result<Self, FormError> {
+        let accepted = bool::from_form_fields(fields, name.clone())?;
+        Accept::try_from(accepted).map_err(|err| FieldError::new(name, err).into())
+    }
+}
+
 impl Form for Accept {
     fn view(
         field: crate::FieldConfiguration,
@@ -41,6 +60,7 @@ impl Form for Accept {
                 label=field.label.expect("No label provided")
                 description=field.description
                 name=name
+                validation_mode=field.validation_mode
                 value=value
                 callback={callback.map(|callback| Callback::new(move |v: Result<Self, FieldError>| {
                     callback.run(v.map_err(FormError::from));
@@ -48,4 +68,13 @@ impl Form for Accept {
             />
         }
     }
+
+    fn view_summary(field: crate::FieldConfiguration, _name: Name, value: Self) -> impl IntoView {
+        view! {
+            <SummaryField
+                label=field.label
+                value=t(if *value { FormMessage::Yes } else { FormMessage::No })
+            />
+        }
+    }
 }
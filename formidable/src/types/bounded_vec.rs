@@ -0,0 +1,80 @@
+use bounded_vec::{BoundedVec, BoundedVecOutOfBounds};
+use leptos::prelude::*;
+
+use crate::{
+    types::{std_types::view_items, validate_item_count},
+    FieldConfiguration, FieldError, Form, FormError, FormFields, FromFormFields, LabelledItems,
+    Name, Validate,
+};
+
+/// Converts the items of a list with `L` to `U` items, e.g. after the items were decoded.
+fn bounded<T, const L: usize, const U: usize, W>(
+    items: Vec<T>,
+    name: Name,
+) -> Result<BoundedVec<T, L, U, W>, FormError>
+where
+    BoundedVec<T, L, U, W>: TryFrom<Vec<T>, Error = BoundedVecOutOfBounds>,
+{
+    validate_item_count(items.len(), &name, Some(L), Some(U))?;
+    BoundedVec::try_from(items).map_err(|err| FieldError::new(name, err).into())
+}
+
+impl<T, const L: usize, const U: usize, W> Validate for BoundedVec<T, L, U, W>
+where
+    T: Validate,
+{
+    fn validate(&self, name: Name) -> Result<(), FormError> {
+        self.as_vec().validate(name)
+    }
+}
+
+impl<T, const L: usize, const U: usize, W> FromFormFields for BoundedVec<T, L, U, W>
+where
+    T: FromFormFields,
+    BoundedVec<T, L, U, W>: TryFrom<Vec<T>, Error = BoundedVecOutOfBounds>,
+{
+    fn from_form_fields(fields: &FormFields, name: Name) -> Result<Self, FormError> {
+        bounded(Vec::<T>::from_form_fields(fields, name.clone())?, name)
+    }
+}
+
+impl<T: 'static, const L: usize, const U: usize, W> LabelledItems for BoundedVec<T, L, U, W> {
+    type Item = T;
+}
+
+impl<T, const L: usize, const U: usize, W> Form for BoundedVec<T, L, U, W>
+where
+    T: Form + Clone + Send + Sync + 'static,
+    W: Send + Sync + 'static,
+    BoundedVec<T, L, U, W>: TryFrom<Vec<T>, Error = BoundedVecOutOfBounds>,
+{
+    fn view(
+        field: FieldConfiguration,
+        name: Name,
+        value: Option<Self>,
+        callback: Option<Callback<Result<Self, FormError>>>,
+    ) -> impl IntoView {
+        // The bounds of the type can only be narrowed by the `min_items` and `max_items` attributes
+        let min = field.min_items.map_or(L, |min| min.max(L));
+        let max = field.max_items.map_or(U, |max| max.min(U));
+        let callback = callback.map(|callback| {
+            let name = name.clone();
+            Callback::new(move |items: Result<Vec<T>, FormError>| {
+                callback.run(items.and_then(|items| bounded(items, name.clone())));
+            })
+        });
+
+        view_items(
+            field,
+            name,
+            value.map(BoundedVec::to_vec),
+            callback,
+            Some(min),
+            Some(max),
+        )
+    }
+
+    fn view_summary(field: FieldConfiguration, name: Name, value: Self) -> impl IntoView {
+        Vec::view_summary(field, name, value.to_vec())
+    }
+}
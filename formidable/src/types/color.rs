@@ -1,6 +1,7 @@
 use crate::{
-    components::{Input, InputType},
-    FieldError, Form, FormError, Name,
+    components::{Input, InputType, SummaryField},
+    types::{parse_form_field, validate_from_str},
+    FieldError, Form, FormError, FormFields, FromFormFields, Name, Validate,
 };
 use derive_more::{Deref, Into};
 use leptos::prelude::*;
@@ -56,6 +57,18 @@ impl Default for Color {
     }
 }
 
+impl Validate for Color {
+    fn validate(&self, name: Name) -> Result<(), FormError> {
+        validate_from_str(self, name)
+    }
+}
+
+impl FromFormFields for Color {
+    fn from_form_fields(fields: &FormFields, name: Name) -> Result<Self, FormError> {
+        parse_form_field(fields, name)
+    }
+}
+
 impl Form for Color {
     fn view(
         field: crate::FieldConfiguration,
@@ -68,6 +81,7 @@ impl Form for Color {
                 label=field.label.expect("No label provided")
                 description=field.description
                 name=name
+                validation_mode=field.validation_mode
                 value=value
                 callback={callback.map(|callback| Callback::new(move |v: Result<Self, FieldError>| {
                     callback.run(v.map_err(FormError::from));
@@ -76,4 +90,8 @@ impl Form for Color {
             />
         }
     }
+
+    fn view_summary(field: crate::FieldConfiguration, _name: Name, value: Self) -> impl IntoView {
+        view! { <SummaryField label=field.label value=value.to_string() /> }
+    }
 }
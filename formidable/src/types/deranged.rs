@@ -0,0 +1,326 @@
+use std::{
+    fmt::Display,
+    num::{IntErrorKind, ParseIntError},
+    str::FromStr,
+};
+
+use deranged::{
+    OptionRangedI128, OptionRangedI16, OptionRangedI32, OptionRangedI64, OptionRangedI8,
+    OptionRangedIsize, OptionRangedU128, OptionRangedU16, OptionRangedU32, OptionRangedU64,
+    OptionRangedU8, OptionRangedUsize, RangedI128, RangedI16, RangedI32, RangedI64, RangedI8,
+    RangedIsize, RangedU128, RangedU16, RangedU32, RangedU64, RangedU8, RangedUsize,
+};
+use leptos::prelude::*;
+use thiserror::Error;
+
+use crate::{
+    components::{Input, InputType},
+    types::{option::view_option, EmptyIsNone, FormType},
+    FieldConfiguration, FieldError, Form, FormError, FormFields, FromFormFields, Name, Validate,
+};
+
+#[derive(Debug, Clone, Copy, Error, PartialEq, Eq, Hash)]
+pub enum RangedError<T: Display> {
+    #[error("Input cannot be empty")]
+    IsEmpty,
+    #[error("Invalid format")]
+    InvalidFormat,
+    #[error("Must be between {0} and {1}")]
+    OutOfRange(T, T),
+}
+
+/// Integers with bounds, which can be rendered as a range slider with `render_as = "slider"`.
+pub trait Slider: Form {
+    fn view_slider(
+        field: FieldConfiguration,
+        name: Name,
+        value: Option<Self>,
+        callback: Option<Callback<Result<Self, FormError>>>,
+    ) -> impl IntoView;
+}
+
+/// A slider of an optional value, which is `None` unless the checkbox or "Add" button of the
+/// field is selected, as a slider is never empty. `presence = "empty_is_none"` is rejected by
+/// `#[derive(Form)]`.
+impl<T> Slider for Option<T>
+where
+    T: Slider + Clone,
+{
+    fn view_slider(
+        field: FieldConfiguration,
+        name: Name,
+        value: Option<Self>,
+        callback: Option<Callback<Result<Self, FormError>>>,
+    ) -> impl IntoView {
+        view_option(field, name, value, callback, T::view_slider)
+    }
+}
+
+/// The input of a ranged integer, whose errors name the bounds of the integer.
+#[derive(Debug, Clone, Copy)]
+struct Ranged<T>(T);
+
+/// Parses an integer between `min` and `max`, reporting numbers that do not even fit the
+/// integer type as out of range as well, including negative numbers for unsigned types.
+fn parse_int<T>(s: &str, min: T, max: T) -> Result<T, RangedError<T>>
+where
+    T: FromStr<Err = ParseIntError> + PartialOrd + Display + Copy,
+{
+    let value = s.parse::<T>().map_err(|err| match err.kind() {
+        IntErrorKind::Empty => RangedError::IsEmpty,
+        IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => RangedError::OutOfRange(min, max),
+        // Unsigned types reject the sign of negative numbers as an invalid digit
+        IntErrorKind::InvalidDigit if is_negative_number(s) => RangedError::OutOfRange(min, max),
+        _ => RangedError::InvalidFormat,
+    })?;
+    if value < min || value > max {
+        return Err(RangedError::OutOfRange(min, max));
+    }
+    Ok(value)
+}
+
+/// Whether `s` is a number below zero, such as `-1`.
+fn is_negative_number(s: &str) -> bool {
+    s.strip_prefix('-').is_some_and(|digits| {
+        digits.bytes().all(|digit| digit.is_ascii_digit())
+            && digits.bytes().any(|digit| digit != b'0')
+    })
+}
+
+/// Implements `Form`, `Validate` and `FromFormFields` by way of the input of `Ranged<$type>`.
+macro_rules! impl_form_by_ranged_input {
+    ($type:ident, $internal:ty) => {
+        impl<const MIN: $internal, const MAX: $internal> Form for $type<MIN, MAX> {
+            const SINGLE_INPUT: bool = true;
+
+            fn view(
+                field: FieldConfiguration,
+                name: Name,
+                value: Option<Self>,
+                callback: Option<Callback<Result<Self, FormError>>>,
+            ) -> impl IntoView {
+                let callback = callback.map(|callback| {
+                    Callback::new(move |v: Result<Ranged<Self>, FormError>| {
+                        callback.run(v.map(|v| v.0));
+                    })
+                });
+                Ranged::view(field, name, value.map(Ranged), callback)
+            }
+
+            fn view_summary(field: FieldConfiguration, name: Name, value: Self) -> impl IntoView {
+                Ranged::view_summary(field, name, Ranged(value))
+            }
+        }
+
+        impl<const MIN: $internal, const MAX: $internal> Validate for $type<MIN, MAX> {
+            fn validate(&self, name: Name) -> Result<(), FormError> {
+                Ranged(*self).validate(name)
+            }
+        }
+
+        impl<const MIN: $internal, const MAX: $internal> FromFormFields for $type<MIN, MAX> {
+            fn from_form_fields(fields: &FormFields, name: Name) -> Result<Self, FormError> {
+                Ranged::<Self>::from_form_fields(fields, name).map(|v| v.0)
+            }
+        }
+    };
+}
+
+macro_rules! impl_form_for_ranged {
+    ($($ranged:ident, $option_ranged:ident, $internal:ty;)*) => {
+        $(
+            impl<const MIN: $internal, const MAX: $internal> Display for Ranged<$ranged<MIN, MAX>> {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    self.0.fmt(f)
+                }
+            }
+
+            impl<const MIN: $internal, const MAX: $internal> FromStr for Ranged<$ranged<MIN, MAX>> {
+                type Err = RangedError<$internal>;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    let value = parse_int(s, MIN, MAX)?;
+                    $ranged::new(value)
+                        .map(Ranged)
+                        .ok_or(RangedError::OutOfRange(MIN, MAX))
+                }
+            }
+
+            impl<const MIN: $internal, const MAX: $internal> FormType for Ranged<$ranged<MIN, MAX>> {
+                const INPUT_TYPE: InputType = InputType::Number;
+                const REQUIRED: Option<bool> = Some(true);
+                const MIN: Option<Self> = Some(Ranged($ranged::MIN));
+                const MAX: Option<Self> = Some(Ranged($ranged::MAX));
+            }
+
+            impl_form_by_ranged_input!($ranged, $internal);
+
+            impl<const MIN: $internal, const MAX: $internal> Slider for $ranged<MIN, MAX> {
+                fn view_slider(
+                    field: FieldConfiguration,
+                    name: Name,
+                    value: Option<Self>,
+                    callback: Option<Callback<Result<Self, FormError>>>,
+                ) -> impl IntoView {
+                    view! {
+                        <Input
+                            label=field.label.expect("No label provided")
+                            description=field.description
+                            name=name
+                            validation_mode=field.validation_mode
+                            value=value.map(Ranged)
+                            callback={callback.map(|callback| Callback::new(move |v: Result<Ranged<Self>, FieldError>| {
+                                callback.run(v.map(|v| v.0).map_err(FormError::from));
+                            }))}
+                            input_type=InputType::Range
+                            required=Some(true)
+                            min=Some(Ranged(Self::MIN))
+                            max=Some(Ranged(Self::MAX))
+                            class=field.class
+                            colspan=field.colspan
+                        />
+                    }
+                }
+            }
+
+            // An empty input is `None`
+            impl<const MIN: $internal, const MAX: $internal> Display for Ranged<$option_ranged<MIN, MAX>> {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    match self.0.get() {
+                        Some(value) => value.fmt(f),
+                        None => Ok(()),
+                    }
+                }
+            }
+
+            impl<const MIN: $internal, const MAX: $internal> FromStr for Ranged<$option_ranged<MIN, MAX>> {
+                type Err = RangedError<$internal>;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    if s.is_empty() {
+                        return Ok(Ranged($option_ranged::None));
+                    }
+                    let Ranged(value) = s.parse::<Ranged<$ranged<MIN, MAX>>>()?;
+                    Ok(Ranged($option_ranged::Some(value)))
+                }
+            }
+
+            impl<const MIN: $internal, const MAX: $internal> FormType for Ranged<$option_ranged<MIN, MAX>> {
+                const INPUT_TYPE: InputType = InputType::Number;
+                const REQUIRED: Option<bool> = Some(false);
+                const MIN: Option<Self> = Some(Ranged($option_ranged::Some($ranged::MIN)));
+                const MAX: Option<Self> = Some(Ranged($option_ranged::Some($ranged::MAX)));
+            }
+
+            impl_form_by_ranged_input!($option_ranged, $internal);
+
+            // The `Option` variants are rendered as a single input, whose empty input is `None`
+            impl<const MIN: $internal, const MAX: $internal> EmptyIsNone for $option_ranged<MIN, MAX> {
+                const ASSERT_SINGLE_INPUT: () = ();
+            }
+
+            impl<const MIN: $internal, const MAX: $internal> Slider for $option_ranged<MIN, MAX> {
+                fn view_slider(
+                    field: FieldConfiguration,
+                    name: Name,
+                    value: Option<Self>,
+                    callback: Option<Callback<Result<Self, FormError>>>,
+                ) -> impl IntoView {
+                    let callback = callback.map(|callback| {
+                        Callback::new(move |v: Result<Option<$ranged<MIN, MAX>>, FormError>| {
+                            callback.run(v.map(Self::from));
+                        })
+                    });
+                    Option::view_slider(field, name, value.map(Self::get), callback)
+                }
+            }
+        )*
+    };
+}
+
+impl_form_for_ranged!(
+    RangedU8, OptionRangedU8, u8;
+    RangedU16, OptionRangedU16, u16;
+    RangedU32, OptionRangedU32, u32;
+    RangedU64, OptionRangedU64, u64;
+    RangedU128, OptionRangedU128, u128;
+    RangedUsize, OptionRangedUsize, usize;
+    RangedI8, OptionRangedI8, i8;
+    RangedI16, OptionRangedI16, i16;
+    RangedI32, OptionRangedI32, i32;
+    RangedI64, OptionRangedI64, i64;
+    RangedI128, OptionRangedI128, i128;
+    RangedIsize, OptionRangedIsize, isize;
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_int_within_bounds() {
+        assert_eq!(parse_int::<u8>("1", 1, 10), Ok(1));
+        assert_eq!(parse_int::<u8>("10", 1, 10), Ok(10));
+        assert_eq!(parse_int::<i8>("-5", -5, 5), Ok(-5));
+    }
+
+    #[test]
+    fn parse_int_errors() {
+        assert_eq!(parse_int::<u8>("", 1, 10), Err(RangedError::IsEmpty));
+        assert_eq!(
+            parse_int::<u8>("1.5", 1, 10),
+            Err(RangedError::InvalidFormat)
+        );
+        assert_eq!(
+            parse_int::<u8>("0", 1, 10),
+            Err(RangedError::OutOfRange(1, 10))
+        );
+        assert_eq!(
+            parse_int::<u8>("11", 1, 10),
+            Err(RangedError::OutOfRange(1, 10))
+        );
+        // Numbers that do not fit the type are out of range as well
+        assert_eq!(
+            parse_int::<u8>("256", 1, 10),
+            Err(RangedError::OutOfRange(1, 10))
+        );
+        assert_eq!(
+            parse_int::<u8>("-1", 1, 10),
+            Err(RangedError::OutOfRange(1, 10))
+        );
+        assert_eq!(
+            parse_int::<u8>("-a", 1, 10),
+            Err(RangedError::InvalidFormat)
+        );
+        assert_eq!(
+            parse_int::<i8>("-129", -5, 5),
+            Err(RangedError::OutOfRange(-5, 5))
+        );
+    }
+
+    #[test]
+    fn ranged_integers_are_map_keys() {
+        use std::collections::BTreeMap;
+
+        let fields = FormFields::from_iter([
+            ("map[keys][%33]", "3"),
+            ("map[values][%33]", "a"),
+            ("map[keys][%311]", "11"),
+            ("map[values][%311]", "b"),
+        ]);
+        let err = BTreeMap::<RangedU8<1, 10>, String>::from_form_fields(&fields, Name::from("map"))
+            .unwrap_err();
+        assert_eq!(err.len(), 1);
+        let err = err.iter().next().unwrap();
+        assert_eq!(err.name(), Name::from("map[keys][%311]"));
+        assert_eq!(err.to_string().trim(), "Must be between 1 and 10");
+
+        let fields = FormFields::from_iter([("map[keys][%33]", "3"), ("map[values][%33]", "a")]);
+        let map = BTreeMap::<RangedU8<1, 10>, String>::from_form_fields(&fields, Name::from("map"))
+            .unwrap();
+        assert_eq!(
+            map,
+            BTreeMap::from([(RangedU8::new_static::<3>(), "a".to_string())])
+        );
+    }
+}
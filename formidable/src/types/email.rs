@@ -7,6 +7,7 @@ use crate::{components::InputType, types::FormType};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Display, Into, Deref)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "String"))]
 pub struct Email(String);
 
 #[derive(Debug, Clone, Copy, Error, PartialEq, Eq, Hash)]
@@ -27,6 +28,14 @@ impl FromStr for Email {
     }
 }
 
+impl TryFrom<String> for Email {
+    type Error = EmailError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
 impl FormType for Email {
     const INPUT_TYPE: InputType = InputType::Email;
     const REQUIRED: Option<bool> = Some(true);
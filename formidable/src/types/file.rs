@@ -4,7 +4,10 @@ use leptos::prelude::*;
 
 use thiserror::Error;
 
-use crate::{components::FileInput, FieldError, Form, FormError, Name};
+use crate::{
+    components::{FileInput, SummaryField},
+    FieldError, Form, FormError, FormFields, FromFormFields, Name, Validate,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -54,6 +57,22 @@ pub enum FileError {
     NoFileSelected,
 }
 
+impl Validate for File {
+    fn validate(&self, _name: Name) -> Result<(), FormError> {
+        Ok(())
+    }
+}
+
+impl FromFormFields for File {
+    fn from_form_fields(fields: &FormFields, name: Name) -> Result<Self, FormError> {
+        // Only `multipart/form-data` submissions contain files
+        fields
+            .get_file(&name)
+            .cloned()
+            .ok_or_else(|| FieldError::new(name, FileError::NoFileSelected).into())
+    }
+}
+
 impl Form for File {
     fn view(
         field: crate::FieldConfiguration,
@@ -66,6 +85,7 @@ impl Form for File {
                 label=field.label.expect("No label provided")
                 description=field.description
                 name=name
+                validation_mode=field.validation_mode
                 value=value
                 callback={callback.map(|callback| Callback::new(move |v: Result<Self, FieldError>| {
                     callback.run(v.map_err(FormError::from));
@@ -73,4 +93,8 @@ impl Form for File {
             />
         }
     }
+
+    fn view_summary(field: crate::FieldConfiguration, _name: Name, value: Self) -> impl IntoView {
+        view! { <SummaryField label=field.label value=value.to_string() /> }
+    }
 }
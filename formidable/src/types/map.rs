@@ -0,0 +1,575 @@
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt::Display,
+    hash::{BuildHasher, Hash},
+};
+
+use leptos::{context::Provider, prelude::*};
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::{
+    components::{Section, SummaryField, SummarySection},
+    draft::Draft,
+    draft_value, persist_draft_value, t, use_form_context, FieldConfiguration, FieldError, Form,
+    FormError, FormFields, FormMessage, FromFormFields, Name, Validate,
+};
+
+#[derive(Debug, Clone, Copy, Error, PartialEq, Eq, Hash)]
+pub enum MapError {
+    #[error("Duplicate key")]
+    DuplicateKey,
+}
+
+/// The name segment of the entry with `key`, e.g. `Content-Type`. Brackets, commas and `%`
+/// are percent-encoded, as is the first digit of keys consisting of digits only, which would
+/// be parsed as an index. The empty key is `%`.
+fn key_segment(key: &str) -> String {
+    if key.is_empty() {
+        return "%".to_owned();
+    }
+    let digits_only = key.bytes().all(|byte| byte.is_ascii_digit());
+    let mut segment = String::with_capacity(key.len());
+    for (position, c) in key.chars().enumerate() {
+        match c {
+            '%' | '[' | ']' | ',' => segment.push_str(&format!("%{:02X}", c as u8)),
+            _ if digits_only && position == 0 => segment.push_str(&format!("%{:02X}", c as u8)),
+            _ => segment.push(c),
+        }
+    }
+    segment
+}
+
+/// The name of the key input of the row named `segment`, e.g. `headers[keys][Content-Type]`.
+fn key_name(name: &Name, segment: &str) -> Name {
+    name.push_key("keys").push_key(segment)
+}
+
+/// The name of the value of the row named `segment`, e.g. `headers[values][Content-Type]`.
+fn value_name(name: &Name, segment: &str) -> Name {
+    name.push_key("values").push_key(segment)
+}
+
+fn validate_entries<'a, K, V>(
+    entries: impl Iterator<Item = (&'a K, &'a V)>,
+    name: Name,
+) -> Result<(), FormError>
+where
+    K: Validate + Display + 'a,
+    V: Validate + 'a,
+{
+    let mut errors = Vec::new();
+    for (key, value) in entries {
+        let segment = key_segment(&key.to_string());
+        if let Err(err) = key.validate(key_name(&name, &segment)) {
+            errors.extend(err);
+        }
+        if let Err(err) = value.validate(value_name(&name, &segment)) {
+            errors.extend(err);
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(FormError::from(errors))
+    }
+}
+
+fn entries_from_form_fields<K, V, M>(fields: &FormFields, name: Name) -> Result<M, FormError>
+where
+    K: FromFormFields + Display,
+    V: FromFormFields,
+    M: FromIterator<(K, V)>,
+{
+    let mut entries = Vec::new();
+    let mut errors = Vec::new();
+    let mut keys = HashSet::new();
+
+    // Rows keep the segment they were rendered with, even if their key was changed since
+    for segment in fields.keys_below(&name.push_key("keys")) {
+        let key_name = key_name(&name, &segment);
+        let key = match K::from_form_fields(fields, key_name.clone()) {
+            Ok(key) => key,
+            Err(err) => {
+                errors.extend(err);
+                continue;
+            }
+        };
+        if !keys.insert(key.to_string()) {
+            errors.push(FieldError::new(key_name, MapError::DuplicateKey));
+            continue;
+        }
+        match V::from_form_fields(fields, value_name(&name, &segment)) {
+            Ok(value) => entries.push((key, value)),
+            Err(err) => errors.extend(err),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(entries.into_iter().collect())
+    } else {
+        Err(FormError::from(errors))
+    }
+}
+
+#[derive(Clone)]
+struct Row<K, V> {
+    id: Uuid,
+    /// The segment the inputs are named after, see [`key_segment`]. Rows are renamed when their
+    /// key input loses focus, new rows are named `%new0`, `%new1` and so on until then.
+    segment: String,
+    /// Whether the row was renamed, so its fields restore their raw values from the draft
+    renamed: bool,
+    key: Option<Result<K, FormError>>,
+    /// The input of the key, which duplicates are detected by
+    raw_key: String,
+    value: Option<Result<V, FormError>>,
+}
+
+impl<K, V> Row<K, V> {
+    fn new(segment: String) -> Self {
+        Row {
+            id: Uuid::new_v4(),
+            segment,
+            renamed: false,
+            key: None,
+            raw_key: String::new(),
+            value: None,
+        }
+    }
+}
+
+/// Whether the valid key of `row` is already used by a preceding row, or `None` if the key of
+/// `row` isn't valid.
+fn is_duplicate<K, V>(rows: &[Row<K, V>], id: Uuid) -> Option<bool> {
+    let position = rows.iter().position(|row| row.id == id)?;
+    let row = &rows[position];
+    matches!(row.key, Some(Ok(_))).then(|| {
+        rows[..position]
+            .iter()
+            .any(|other| matches!(other.key, Some(Ok(_))) && other.raw_key == row.raw_key)
+    })
+}
+
+/// Renders the entries of a map as rows of a key input and the form of the value, which can
+/// be added and removed.
+fn view_entries<K, V, M>(
+    field: FieldConfiguration,
+    name: Name,
+    value: Option<Vec<(K, V)>>,
+    callback: Option<Callback<Result<M, FormError>>>,
+) -> impl IntoView
+where
+    K: Form + Display + Clone,
+    V: Form + Clone,
+    M: FromIterator<(K, V)> + 'static,
+{
+    // A restored draft only contains the segments of the rows, the rows restore their own values
+    let restored = draft_value(&name).map(|segments| {
+        segments
+            .split(',')
+            .filter(|segment| !segment.is_empty())
+            .map(|segment| Row::new(segment.to_owned()))
+            .collect::<Vec<_>>()
+    });
+    let rows: RwSignal<Vec<Row<K, V>>> = RwSignal::new(restored.unwrap_or_else(|| {
+        let mut entries = value.unwrap_or_default();
+        // Keeps the order of `HashMap`s stable between server and client
+        entries.sort_by_cached_key(|(key, _)| key.to_string());
+        entries
+            .into_iter()
+            .map(|(key, value)| Row {
+                id: Uuid::new_v4(),
+                segment: key_segment(&key.to_string()),
+                renamed: false,
+                raw_key: key.to_string(),
+                key: Some(Ok(key)),
+                value: Some(Ok(value)),
+            })
+            .collect()
+    }));
+    let next_new = StoredValue::new(rows.with_untracked(|rows| {
+        rows.iter()
+            .filter_map(|row| row.segment.strip_prefix("%new")?.parse::<usize>().ok())
+            .map(|index| index + 1)
+            .max()
+            .unwrap_or(0)
+    }));
+    persist_draft_value(name.clone(), move || {
+        rows.with(|rows| {
+            rows.iter()
+                .map(|row| row.segment.as_str())
+                .collect::<Vec<_>>()
+                .join(",")
+        })
+    });
+    // Rows keep their raw values when they are renamed, also outside of a form
+    let draft = use_context::<Draft>().unwrap_or_else(Draft::in_memory);
+
+    if let Some(callback) = callback {
+        let name = name.clone();
+        Effect::new(move |_| {
+            let result = rows.with(|rows| {
+                let mut entries = Vec::new();
+                let mut errors = Vec::new();
+                let mut keys = HashSet::new();
+
+                for row in rows {
+                    if let Some(Err(err)) = &row.key {
+                        errors.extend(err.clone());
+                    }
+                    if let Some(Err(err)) = &row.value {
+                        errors.extend(err.clone());
+                    }
+                    if let Some(Ok(key)) = &row.key {
+                        if !keys.insert(row.raw_key.as_str()) {
+                            errors.push(FieldError::new(
+                                key_name(&name, &row.segment),
+                                MapError::DuplicateKey,
+                            ));
+                        } else if let Some(Ok(value)) = &row.value {
+                            entries.push((key.clone(), value.clone()));
+                        }
+                    }
+                }
+
+                if errors.is_empty() {
+                    Ok(entries.into_iter().collect::<M>())
+                } else {
+                    Err(FormError::from(errors))
+                }
+            });
+            callback.run(result);
+        });
+    }
+
+    let validation_mode = field.validation_mode;
+
+    view! {
+        <Section name=name.clone() heading={field.label.expect("No label provided").clone()} class="map".to_string()>
+            <For
+                each={move || rows.get()}
+                key={move |row| (row.id, row.segment.clone())}
+                children={move |row| {
+                    let id = row.id;
+                    // Named after the key once its input loses focus, so the row isn't rendered
+                    // again while the key is typed. Rows with invalid or duplicate keys keep
+                    // their name.
+                    let rename = {
+                        let name = name.clone();
+                        move || {
+                            let renamed = rows.with_untracked(|rows| {
+                                let row = rows.iter().find(|row| row.id == id)?;
+                                if !matches!(row.key, Some(Ok(_))) {
+                                    return None;
+                                }
+                                let segment = key_segment(&row.raw_key);
+                                (segment != row.segment && rows.iter().all(|other| other.segment != segment))
+                                    .then(|| (row.segment.clone(), segment))
+                            });
+                            let Some((old, new)) = renamed else {
+                                return;
+                            };
+                            draft.rename(&key_name(&name, &old), &key_name(&name, &new));
+                            draft.rename(&value_name(&name, &old), &value_name(&name, &new));
+                            rows.update(|rows| {
+                                if let Some(row) = rows.iter_mut().find(|row| row.id == id) {
+                                    row.segment = new;
+                                    row.renamed = true;
+                                }
+                            });
+                        }
+                    };
+                    let row_draft = if row.renamed { draft.restoring() } else { draft };
+                    let key_name = key_name(&name, &row.segment);
+                    let value_name = value_name(&name, &row.segment);
+                    let duplicate = Memo::new(move |_| rows.with(|rows| is_duplicate(rows, id)) == Some(true));
+
+                    // Report duplicates as an error of the key input, so they are revealed and
+                    // focused like its other errors
+                    let form_context = use_form_context();
+                    let duplicate_name = key_name.clone();
+                    Effect::new(move |_| {
+                        let is_duplicate = rows.with(|rows| is_duplicate(rows, id));
+                        let Some(field) = form_context.and_then(|form_context| form_context.field(&duplicate_name)) else {
+                            return;
+                        };
+                        match is_duplicate {
+                            Some(true) => field.error().set(Some(FieldError::new(duplicate_name.clone(), MapError::DuplicateKey))),
+                            Some(false) => field.error().set(None),
+                            // Invalid keys report their own error
+                            None => {}
+                        }
+                    });
+
+                    let key_callback = Callback::new(move |key: Result<K, FormError>| {
+                        let raw_key = match &key {
+                            Ok(key) => key.to_string(),
+                            Err(err) => err
+                                .iter()
+                                .find_map(|err| err.raw_value())
+                                .unwrap_or_default()
+                                .to_owned(),
+                        };
+                        rows.update(|rows| {
+                            if let Some(row) = rows.iter_mut().find(|row| row.id == id) {
+                                row.key = Some(key);
+                                row.raw_key = raw_key;
+                            }
+                        });
+                    });
+                    let value_callback = Callback::new(move |value: Result<V, FormError>| {
+                        rows.update(|rows| {
+                            if let Some(row) = rows.iter_mut().find(|row| row.id == id) {
+                                row.value = Some(value);
+                            }
+                        });
+                    });
+                    let key = row.key.and_then(Result::ok);
+                    let value = row.value.and_then(Result::ok);
+
+                    view! {
+                        <Provider value=row_draft>
+                            <div class={format!("map-entry item-{}", id)}>
+                                <div class="map-key" on:focusout=move |_| rename()>
+                                    {K::view(FieldConfiguration {
+                                        label: Some(t(FormMessage::MapKeyLabel).into()),
+                                        validation_mode,
+                                        ..Default::default()
+                                    }, key_name, key, Some(key_callback))}
+                                    {move || duplicate.get().then(|| view! {
+                                        <p class="message error-message">{MapError::DuplicateKey.to_string()}</p>
+                                    })}
+                                </div>
+                                <div class="map-value">
+                                    {V::view(FieldConfiguration {
+                                        label: Some(t(FormMessage::MapValueLabel).into()),
+                                        validation_mode,
+                                        ..Default::default()
+                                    }, value_name, value, Some(value_callback))}
+                                </div>
+                                <button
+                                    type="button"
+                                    class="map-remove-button"
+                                    on:click={move |_| {
+                                        rows.update(move |rows| {
+                                            rows.retain(|row| row.id != id);
+                                        });
+                                    }}
+                                >{t(FormMessage::RemoveButton)}</button>
+                            </div>
+                        </Provider>
+                    }
+                }}
+            />
+            <button
+                type="button"
+                class="map-add-button"
+                on:click={move |_| {
+                    let index = next_new.get_value();
+                    next_new.set_value(index + 1);
+                    rows.update(move |rows| {
+                        rows.push(Row::new(format!("%new{}", index)));
+                    });
+                }}
+            >{t(FormMessage::AddButton)}</button>
+        </Section>
+    }
+}
+
+fn view_entries_summary<K, V>(
+    field: FieldConfiguration,
+    name: Name,
+    entries: Vec<(K, V)>,
+) -> impl IntoView
+where
+    K: Display,
+    V: Form,
+{
+    let mut entries = entries
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), value))
+        .collect::<Vec<_>>();
+    entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    view! {
+        <SummarySection name=name.clone() heading={field.label} class={field.class} colspan={field.colspan}>
+            { if entries.is_empty() {
+                view! { <SummaryField value=t(FormMessage::NotProvided) /> }.into_any()
+            } else {
+                entries.into_iter().map(|(key, value)| {
+                    let value_name = value_name(&name, &key_segment(&key));
+                    view! {
+                        <div class="map-entry">
+                            {V::view_summary(FieldConfiguration {
+                                label: Some(key.into()),
+                                ..Default::default()
+                            }, value_name, value)}
+                        </div>
+                    }
+                }).collect::<Vec<_>>().into_any()
+            }}
+        </SummarySection>
+    }
+}
+
+impl<K, V, S> Validate for HashMap<K, V, S>
+where
+    K: Validate + Display,
+    V: Validate,
+{
+    fn validate(&self, name: Name) -> Result<(), FormError> {
+        validate_entries(self.iter(), name)
+    }
+}
+
+impl<K, V> Validate for BTreeMap<K, V>
+where
+    K: Validate + Display,
+    V: Validate,
+{
+    fn validate(&self, name: Name) -> Result<(), FormError> {
+        validate_entries(self.iter(), name)
+    }
+}
+
+impl<K, V, S> FromFormFields for HashMap<K, V, S>
+where
+    K: FromFormFields + Display + Eq + Hash,
+    V: FromFormFields,
+    S: BuildHasher + Default,
+{
+    fn from_form_fields(fields: &FormFields, name: Name) -> Result<Self, FormError> {
+        entries_from_form_fields(fields, name)
+    }
+}
+
+impl<K, V> FromFormFields for BTreeMap<K, V>
+where
+    K: FromFormFields + Display + Ord,
+    V: FromFormFields,
+{
+    fn from_form_fields(fields: &FormFields, name: Name) -> Result<Self, FormError> {
+        entries_from_form_fields(fields, name)
+    }
+}
+
+impl<K, V, S> Form for HashMap<K, V, S>
+where
+    K: Form + Display + Clone + Eq + Hash,
+    V: Form + Clone,
+    S: BuildHasher + Default + Send + Sync + 'static,
+{
+    fn view(
+        field: FieldConfiguration,
+        name: Name,
+        value: Option<Self>,
+        callback: Option<Callback<Result<Self, FormError>>>,
+    ) -> impl IntoView {
+        view_entries(
+            field,
+            name,
+            value.map(|value| value.into_iter().collect()),
+            callback,
+        )
+    }
+
+    fn view_summary(field: FieldConfiguration, name: Name, value: Self) -> impl IntoView {
+        view_entries_summary(field, name, value.into_iter().collect())
+    }
+}
+
+impl<K, V> Form for BTreeMap<K, V>
+where
+    K: Form + Display + Clone + Ord,
+    V: Form + Clone,
+{
+    fn view(
+        field: FieldConfiguration,
+        name: Name,
+        value: Option<Self>,
+        callback: Option<Callback<Result<Self, FormError>>>,
+    ) -> impl IntoView {
+        view_entries(
+            field,
+            name,
+            value.map(|value| value.into_iter().collect()),
+            callback,
+        )
+    }
+
+    fn view_summary(field: FieldConfiguration, name: Name, value: Self) -> impl IntoView {
+        view_entries_summary(field, name, value.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn key_segments_are_escaped() {
+        assert_eq!(key_segment("Content-Type"), "Content-Type");
+        assert_eq!(key_segment("a[b]"), "a%5Bb%5D");
+        assert_eq!(key_segment("50%,x"), "50%25%2Cx");
+        assert_eq!(key_segment("42"), "%342");
+        assert_eq!(key_segment("4a"), "4a");
+        assert_eq!(key_segment(""), "%");
+        let name = value_name(&Name::from("map"), &key_segment("a[b]"));
+        assert_eq!(Name::parse(&name.to_string()), Ok(name));
+    }
+
+    #[test]
+    fn entries_are_decoded_by_row_segment() {
+        let fields = FormFields::from_iter([
+            ("map[keys][keys]", "keys"),
+            ("map[values][keys]", "1"),
+            ("map[keys][%37]", "7"),
+            ("map[values][%37]", "2"),
+            ("map[keys][a%5Bb%5D]", "a[b]"),
+            ("map[values][a%5Bb%5D]", "3"),
+            // Rows keep their segment if the key was changed without JavaScript
+            ("map[keys][%]", "renamed"),
+            ("map[values][%]", "4"),
+        ]);
+        let map = BTreeMap::<String, u32>::from_form_fields(&fields, Name::from("map")).unwrap();
+        assert_eq!(
+            map,
+            BTreeMap::from([
+                ("keys".to_string(), 1),
+                ("7".to_string(), 2),
+                ("a[b]".to_string(), 3),
+                ("renamed".to_string(), 4),
+            ])
+        );
+    }
+
+    #[test]
+    fn duplicate_keys_are_errors_of_the_key() {
+        let fields = FormFields::from_iter([
+            ("map[keys][a]", "a"),
+            ("map[values][a]", "1"),
+            ("map[keys][b]", "a"),
+            ("map[values][b]", "2"),
+        ]);
+        let err =
+            BTreeMap::<String, u32>::from_form_fields(&fields, Name::from("map")).unwrap_err();
+        assert_eq!(err.len(), 1);
+        assert_eq!(
+            err.iter().next().unwrap().name(),
+            Name::from("map").push_key("keys").push_key("b")
+        );
+    }
+
+    #[test]
+    fn errors_of_values_refer_to_their_key() {
+        let fields = FormFields::from_iter([("map[keys][a]", "a"), ("map[values][a]", "x")]);
+        let err =
+            BTreeMap::<String, u32>::from_form_fields(&fields, Name::from("map")).unwrap_err();
+        assert_eq!(
+            err.iter().next().unwrap().name(),
+            Name::from("map[values][a]")
+        );
+    }
+}
@@ -7,6 +7,7 @@ use crate::{components::InputType, types::FormType};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Display, Into, Deref)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "String"))]
 pub struct NonEmptyString(String);
 
 #[derive(Debug, Clone, Copy, Error, PartialEq, Eq, Hash)]
@@ -27,6 +28,14 @@ impl FromStr for NonEmptyString {
     }
 }
 
+impl TryFrom<String> for NonEmptyString {
+    type Error = NonEmptyStringError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
 impl FormType for NonEmptyString {
     const INPUT_TYPE: InputType = InputType::Text;
     const REQUIRED: Option<bool> = Some(true);
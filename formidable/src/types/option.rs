@@ -1,13 +1,66 @@
 use std::{fmt::Debug, fmt::Display, str::FromStr};
 
 use crate::{
-    components::Checkbox, FieldConfiguration, FieldError, Form, FormConfiguration, FormError,
+    components::{Checkbox, OptionalInput, SummaryField},
+    draft_value, persist_draft_value, t, FieldConfiguration, FieldContext, FieldError, Form,
+    FormConfiguration, FormError, FormFields, FormMessage, FromFormFields, Presence, Validate,
 };
 use derive_more::{Deref, Into};
-use leptos::prelude::*;
+use leptos::{context::Provider, prelude::*};
 
 use crate::{components::InputType, types::FormType};
 
+impl<T> Validate for Option<T>
+where
+    T: Validate,
+{
+    fn validate(&self, name: crate::Name) -> Result<(), FormError> {
+        match self {
+            Some(value) => value.validate(name),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T> FromFormFields for Option<T>
+where
+    T: FromFormFields,
+{
+    fn from_form_fields(fields: &FormFields, name: crate::Name) -> Result<Self, FormError> {
+        let selected = name.push_key("selected");
+        let is_selected = if fields.contains(&selected) {
+            bool::from_form_fields(fields, selected)?
+        } else {
+            // Without a checkbox or button, as with `Presence::EmptyIsNone`
+            fields.get(&name).is_some_and(|value| !value.is_empty())
+        };
+
+        if is_selected {
+            T::from_form_fields(fields, name).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+/// Types supporting `presence = "empty_is_none"`, whose value is `None` when its input is empty.
+/// Checked by `#[derive(Form)]`, which fails to compile if the value is not rendered as a
+/// single input.
+#[doc(hidden)]
+pub trait EmptyIsNone {
+    const ASSERT_SINGLE_INPUT: ();
+}
+
+impl<T> EmptyIsNone for Option<T>
+where
+    T: Form,
+{
+    const ASSERT_SINGLE_INPUT: () = assert!(
+        T::SINGLE_INPUT,
+        "`presence = \"empty_is_none\"` requires a value rendered as a single input, use `presence = \"checkbox\"` or `presence = \"button\"`"
+    );
+}
+
 impl<T> Form for Option<T>
 where
     T: Form + Clone,
@@ -18,45 +71,182 @@ where
         value: Option<Self>,
         callback: Option<leptos::prelude::Callback<Result<Self, crate::FormError>>>,
     ) -> impl leptos::IntoView {
-        let is_selected = RwSignal::new(value.as_ref().flatten().is_some());
-        let last_value = RwSignal::new(value.flatten());
+        view_option(field, name, value, callback, T::view)
+    }
+
+    fn view_summary(field: FieldConfiguration, name: crate::Name, value: Self) -> impl IntoView {
+        match value {
+            Some(value) => T::view_summary(field, name, value).into_any(),
+            None => view! {
+                <SummaryField
+                    label=field.label
+                    value=t(FormMessage::NotProvided)
+                    class=field.class
+                    colspan=field.colspan
+                />
+            }
+            .into_any(),
+        }
+    }
+}
+
+/// The signature of `Form::view`, with which the value of an `Option` field is rendered.
+type ViewValue<T, V> =
+    fn(FieldConfiguration, crate::Name, Option<T>, Option<Callback<Result<T, FormError>>>) -> V;
+
+/// Renders an `Option` field whose value, while it is `Some`, is rendered by `view_value`, e.g.
+/// `T::view` or `T::view_slider`.
+pub(crate) fn view_option<T, V>(
+    field: FieldConfiguration,
+    name: crate::Name,
+    value: Option<Option<T>>,
+    callback: Option<Callback<Result<Option<T>, FormError>>>,
+    view_value: ViewValue<T, V>,
+) -> impl IntoView
+where
+    T: Clone + Send + Sync + 'static,
+    V: IntoView + 'static,
+{
+    let presence = field.presence.unwrap_or_default();
+    let is_selected = RwSignal::new(
+        draft_value(&name.push_key("selected"))
+            .map(|selected| selected == "true")
+            .unwrap_or_else(|| value.as_ref().flatten().is_some()),
+    );
+    // Whether the input is empty, with `Presence::EmptyIsNone`
+    let is_empty = RwSignal::new(false);
+    // The last valid value, shown again when the value is removed and added back
+    let last_value = StoredValue::new(value.flatten());
+    // The last result reported by the form of the value, while it is shown
+    let result = StoredValue::new(None::<Result<T, FormError>>);
+
+    let report = move || {
+        let Some(callback) = callback else {
+            return;
+        };
+        let is_some = match presence {
+            Presence::EmptyIsNone => !is_empty.get_untracked(),
+            Presence::Checkbox | Presence::Button => is_selected.get_untracked(),
+        };
+        if !is_some {
+            callback.run(Ok(None));
+        } else if let Some(result) = result.get_value() {
+            callback.run(result.map(Some));
+        }
+    };
+
+    let value_callback = Callback::new(move |value: Result<T, FormError>| {
+        if let Ok(value) = &value {
+            last_value.set_value(Some(value.clone()));
+        }
+        result.set_value(Some(value));
+        report();
+    });
+
+    let set_selected = move |selected: bool| {
+        if !selected {
+            result.set_value(None);
+        }
+        is_selected.set(selected);
+        report();
+    };
+
+    let value_field = FieldConfiguration {
+        label: field.label.clone(),
+        description: field.description.clone(),
+        class: field.class.clone(),
+        placeholder: field.placeholder.clone(),
+        validation_mode: field.validation_mode,
+        ..Default::default()
+    };
+    let value_view = {
+        let name = name.clone();
+        move || {
+            view_value(
+                value_field.clone(),
+                name.clone(),
+                last_value.get_value(),
+                Some(value_callback),
+            )
+        }
+    };
 
-        let callback = callback.map(|cb| Callback::new(move |v| {}));
+    let style = field
+        .colspan
+        .as_ref()
+        .map(|cols| format!("grid-column: span {};", cols));
 
-        view! {
-            <div
-                class="option"
-                style={field.colspan.as_ref().map(|cols| format!("grid-column: span {};", cols))}
-            >
-                // Variant selector
+    match presence {
+        Presence::Checkbox => view! {
+            <div class="option" style=style>
                 <div class="option-state">
                     <Checkbox
                         label=field.label.as_ref().cloned().expect("No label provided")
                         name=name.push_key("selected")
+                        validation_mode=field.validation_mode
                         value=is_selected.get_untracked()
                         callback={Callback::new(move |selected: Result<bool, FieldError>| {
-                            is_selected.set(selected.unwrap_or(false));
+                            set_selected(selected.unwrap_or(false));
                         })}/>
                 </div>
 
-                // Variant-specific form
                 <div class="option-value">
-                    {let field = field.clone(); move || {
-                        if is_selected.get() {
-                            T::view(FieldConfiguration {
-                                label: field.label.clone(),
-                                description: field.description.clone(),
-                                class: field.class.clone(),
-                                colspan: None,
-                                placeholder: field.placeholder.clone(),
-                            }, name, last_value.get(), callback).into_any()
-                        } else {
-                            ().into_any()
+                    {move || is_selected.get().then(value_view.clone())}
+                </div>
+            </div>
+        }
+        .into_any(),
+        Presence::EmptyIsNone => view! {
+            <div class="option option-empty-is-none" style=style>
+                <Provider value=OptionalInput { name, empty: is_empty }>
+                    {value_view()}
+                </Provider>
+            </div>
+        }
+        .into_any(),
+        Presence::Button => {
+            let selected_field = FieldContext::register(name.push_key("selected"));
+            persist_draft_value(name.push_key("selected"), move || {
+                is_selected.get().to_string()
+            });
+            let label = field.label.clone().expect("No label provided");
+
+            view! {
+                <div class="option option-button" style=style>
+                    {move || if is_selected.get() {
+                        view! {
+                            <input type="hidden" name=name.push_key("selected").to_string() value="true" />
+                            <div class="option-value">{value_view()}</div>
+                            <button
+                                type="button"
+                                class="option-remove-button"
+                                on:click=move |_| {
+                                    selected_field.dirty().set(true);
+                                    set_selected(false);
+                                }
+                            >{t(FormMessage::RemoveButton)}</button>
                         }
+                        .into_any()
+                    } else {
+                        let label = label.clone();
+                        view! {
+                            <div class="option-state">
+                                <span class="option-label">{move || label.get()}</span>
+                                <button
+                                    type="button"
+                                    class="option-add-button"
+                                    on:click=move |_| {
+                                        selected_field.dirty().set(true);
+                                        set_selected(true);
+                                    }
+                                >{t(FormMessage::AddButton)}</button>
+                            </div>
+                        }
+                        .into_any()
                     }}
                 </div>
-            </div>
+            }
+            .into_any()
         }
-        .into_any()
     }
 }
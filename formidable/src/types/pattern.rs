@@ -1,32 +1,209 @@
-use std::{fmt::Debug, str::FromStr};
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Display},
+    hash::Hash,
+    marker::PhantomData,
+    ops::Deref,
+    str::FromStr,
+    sync::{OnceLock, PoisonError, RwLock},
+};
 
-use derive_more::{Deref, Display, Into};
+use regex::Regex;
 use thiserror::Error;
 
 use crate::{components::InputType, types::FormType};
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Display, Into, Deref)]
+/// Describes the regular expression a [`Pattern`] has to match. The pattern is used
+/// both for the HTML `pattern` attribute and for validation, and has to match the whole input.
+///
+/// `PATTERN` must be a valid regular expression in the syntax of the `regex` crate that the
+/// browser accepts as well. It can't be checked at compile time; an invalid pattern rejects
+/// every input with [`PatternError::InvalidPattern`] instead of panicking.
+///
+/// ```rust
+/// use formidable::types::{Pattern, PatternSpec};
+///
+/// pub struct SwissPostalCodeSpec;
+///
+/// impl PatternSpec for SwissPostalCodeSpec {
+///     const PATTERN: &'static str = "[1-9][0-9]{3}";
+/// }
+///
+/// pub type SwissPostalCode = Pattern<SwissPostalCodeSpec>;
+/// ```
+pub trait PatternSpec: Send + Sync + 'static {
+    const PATTERN: &'static str;
+    const INPUT_TYPE: InputType = InputType::Text;
+}
+
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct NonEmptyString(String);
+#[cfg_attr(
+    feature = "serde",
+    serde(into = "String", try_from = "String", bound = "P: PatternSpec")
+)]
+pub struct Pattern<P> {
+    value: String,
+    _spec: PhantomData<P>,
+}
 
 #[derive(Debug, Clone, Copy, Error, PartialEq, Eq, Hash)]
-pub enum NonEmptyStringError {
+pub enum PatternError {
     #[error("Input cannot be empty")]
     IsEmpty,
+    #[error("Invalid format")]
+    InvalidFormat,
+    #[error("Invalid pattern")]
+    InvalidPattern,
+}
+
+/// Compiles `pattern` once. Invalid patterns are cached as well, so they aren't compiled
+/// again for every input.
+fn compiled_pattern(pattern: &'static str) -> Option<Regex> {
+    static CACHE: OnceLock<RwLock<HashMap<&'static str, Option<Regex>>>> = OnceLock::new();
+    let cache = CACHE.get_or_init(Default::default);
+
+    if let Some(regex) = cache
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(pattern)
+    {
+        return regex.clone();
+    }
+
+    // The HTML `pattern` attribute is implicitly anchored, do the same here
+    let regex = Regex::new(&format!("^(?:{})$", pattern)).ok();
+    cache
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .insert(pattern, regex.clone());
+    regex
+}
+
+impl<P: PatternSpec> Pattern<P> {
+    pub fn as_str(&self) -> &str {
+        &self.value
+    }
+
+    pub fn into_inner(self) -> String {
+        self.value
+    }
 }
 
-impl FromStr for NonEmptyString {
-    type Err = NonEmptyStringError;
+impl<P: PatternSpec> FromStr for Pattern<P> {
+    type Err = PatternError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let regex = compiled_pattern(P::PATTERN).ok_or(PatternError::InvalidPattern)?;
         if s.is_empty() {
-            Err(NonEmptyStringError::IsEmpty)
+            Err(PatternError::IsEmpty)
+        } else if regex.is_match(s) {
+            Ok(Pattern {
+                value: s.to_string(),
+                _spec: PhantomData,
+            })
         } else {
-            Ok(NonEmptyString(s.to_string()))
+            Err(PatternError::InvalidFormat)
         }
     }
 }
 
-impl FormType for NonEmptyString {
-    const INPUT_TYPE: InputType = InputType::Text;
+impl<P: PatternSpec> TryFrom<String> for Pattern<P> {
+    type Error = PatternError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl<P> Clone for Pattern<P> {
+    fn clone(&self) -> Self {
+        Pattern {
+            value: self.value.clone(),
+            _spec: PhantomData,
+        }
+    }
+}
+
+impl<P> Debug for Pattern<P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Pattern").field(&self.value).finish()
+    }
+}
+
+impl<P> Display for Pattern<P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl<P> PartialEq for Pattern<P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<P> Eq for Pattern<P> {}
+
+impl<P> Hash for Pattern<P> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
+impl<P> Deref for Pattern<P> {
+    type Target = String;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<P> From<Pattern<P>> for String {
+    fn from(pattern: Pattern<P>) -> Self {
+        pattern.value
+    }
+}
+
+impl<P: PatternSpec> FormType for Pattern<P> {
+    const INPUT_TYPE: InputType = P::INPUT_TYPE;
+    const REQUIRED: Option<bool> = Some(true);
+    const PATTERN: Option<&'static str> = Some(P::PATTERN);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct PostalCodeSpec;
+
+    impl PatternSpec for PostalCodeSpec {
+        const PATTERN: &'static str = "[1-9][0-9]{3}";
+    }
+
+    struct InvalidSpec;
+
+    impl PatternSpec for InvalidSpec {
+        const PATTERN: &'static str = "[0-9";
+    }
+
+    #[test]
+    fn pattern_matches_whole_input() {
+        assert!("8000".parse::<Pattern<PostalCodeSpec>>().is_ok());
+        assert_eq!(
+            "80000".parse::<Pattern<PostalCodeSpec>>(),
+            Err(PatternError::InvalidFormat)
+        );
+        assert_eq!(
+            "".parse::<Pattern<PostalCodeSpec>>(),
+            Err(PatternError::IsEmpty)
+        );
+    }
+
+    #[test]
+    fn invalid_pattern_is_an_error() {
+        assert_eq!(
+            "8000".parse::<Pattern<InvalidSpec>>(),
+            Err(PatternError::InvalidPattern)
+        );
+    }
 }
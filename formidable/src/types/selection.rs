@@ -0,0 +1,332 @@
+use std::{
+    collections::{BTreeSet, HashSet},
+    hash::{BuildHasher, Hash},
+};
+
+use leptos::prelude::*;
+use thiserror::Error;
+
+use crate::{
+    components::{MultiSelect, SummaryField},
+    t, FieldConfiguration, FieldError, Form, FormError, FormFields, FormFieldsError, FormMessage,
+    FromFormFields, Name, Validate,
+};
+
+/// Enums without fields, whose variants can be chosen from a fixed list.
+///
+/// Implemented by `#[derive(Form)]` for enums whose variants are all unit variants.
+pub trait UnitEnum: Clone + Send + Sync + 'static {
+    const VARIANTS: &'static [Self];
+
+    /// The name of the variant, which is submitted as the value of the field.
+    fn variant_name(&self) -> &'static str;
+
+    /// The label of the variant shown to the user.
+    fn label(&self) -> String;
+}
+
+/// How the variants of a selection are rendered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SelectionMode {
+    /// A checkbox for each variant.
+    #[default]
+    Checkboxes,
+    /// A `<select multiple>`.
+    SelectMultiple,
+}
+
+/// Configures how many variants of a selection can be chosen, and how they are rendered.
+///
+/// Set with the `render_as = "checkboxes" | "select_multiple"`, `min_selected` and
+/// `max_selected` field attributes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct SelectionConfig {
+    pub render_as: SelectionMode,
+    pub min: Option<usize>,
+    pub max: Option<usize>,
+}
+
+impl SelectionConfig {
+    pub(crate) fn check(&self, count: usize) -> Result<(), SelectionError> {
+        match (self.min, self.max) {
+            (Some(min), _) if count < min => Err(SelectionError::TooFew(min)),
+            (_, Some(max)) if count > max => Err(SelectionError::TooMany(max)),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Error, PartialEq, Eq, Hash)]
+pub enum SelectionError {
+    #[error("Choose at least {0}")]
+    TooFew(usize),
+    #[error("Choose at most {0}")]
+    TooMany(usize),
+}
+
+/// Collections of unit enum variants that can be chosen with a [`MultiSelect`].
+pub trait Selection: Clone + Send + Sync + 'static {
+    type Item: UnitEnum;
+
+    fn items(&self) -> Vec<Self::Item>;
+
+    /// Creates the collection from distinct items, in the order of their declaration.
+    fn from_items(items: Vec<Self::Item>) -> Self;
+}
+
+impl<E> Selection for Vec<E>
+where
+    E: UnitEnum,
+{
+    type Item = E;
+
+    fn items(&self) -> Vec<E> {
+        self.clone()
+    }
+
+    fn from_items(items: Vec<E>) -> Self {
+        items
+    }
+}
+
+impl<E, S> Selection for HashSet<E, S>
+where
+    E: UnitEnum + Eq + Hash,
+    S: BuildHasher + Default + Clone + Send + Sync + 'static,
+{
+    type Item = E;
+
+    fn items(&self) -> Vec<E> {
+        self.iter().cloned().collect()
+    }
+
+    fn from_items(items: Vec<E>) -> Self {
+        items.into_iter().collect()
+    }
+}
+
+impl<E> Selection for BTreeSet<E>
+where
+    E: UnitEnum + Ord,
+{
+    type Item = E;
+
+    fn items(&self) -> Vec<E> {
+        self.iter().cloned().collect()
+    }
+
+    fn from_items(items: Vec<E>) -> Self {
+        items.into_iter().collect()
+    }
+}
+
+/// Whether `variant` is one of `items`, compared by variant name.
+pub(crate) fn is_selected<E: UnitEnum>(items: &[E], variant: &E) -> bool {
+    items
+        .iter()
+        .any(|item| item.variant_name() == variant.variant_name())
+}
+
+/// Renders a selection as a [`MultiSelect`]. Used by the `Form` implementations of sets, and
+/// by `#[derive(Form)]` for fields with selection attributes.
+pub fn view_selection<S, E>(
+    field: FieldConfiguration,
+    name: Name,
+    value: Option<S>,
+    callback: Option<Callback<Result<S, FormError>>>,
+    config: SelectionConfig,
+) -> impl IntoView
+where
+    S: Selection<Item = E>,
+    E: UnitEnum,
+{
+    view! {
+        <MultiSelect<E>
+            label=field.label.expect("No label provided")
+            description=field.description
+            name=name
+            value=value.map(|value| value.items())
+            callback={callback.map(|callback| Callback::new(move |items: Result<Vec<E>, FieldError>| {
+                callback.run(items.map(S::from_items).map_err(FormError::from));
+            }))}
+            render_as=config.render_as
+            min=config.min
+            max=config.max
+            class=field.class
+            colspan=field.colspan
+            validation_mode=field.validation_mode
+        />
+    }
+}
+
+pub fn view_selection_summary<S: Selection>(
+    field: FieldConfiguration,
+    _name: Name,
+    value: S,
+) -> impl IntoView {
+    let items = value.items();
+    let value = if items.is_empty() {
+        t(FormMessage::NotProvided)
+    } else {
+        S::Item::VARIANTS
+            .iter()
+            .filter(|variant| is_selected(&items, variant))
+            .map(UnitEnum::label)
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    view! {
+        <SummaryField
+            label=field.label
+            value=value
+            class=field.class
+            colspan=field.colspan
+        />
+    }
+}
+
+pub fn validate_selection<S: Selection>(
+    value: &S,
+    name: Name,
+    config: SelectionConfig,
+) -> Result<(), FormError> {
+    config
+        .check(value.items().len())
+        .map_err(|err| FieldError::new(name, err).into())
+}
+
+/// Decodes a selection submitted as one value per chosen variant.
+pub fn selection_from_form_fields<S: Selection>(
+    fields: &FormFields,
+    name: Name,
+    config: SelectionConfig,
+) -> Result<S, FormError> {
+    let submitted = fields.get_all(&name);
+    if let Some(unknown) = submitted.iter().find(|submitted| {
+        !S::Item::VARIANTS
+            .iter()
+            .any(|variant| variant.variant_name() == submitted.as_str())
+    }) {
+        return Err(
+            FieldError::new_string(name, FormFieldsError::InvalidVariant, unknown.clone()).into(),
+        );
+    }
+
+    let items = S::Item::VARIANTS
+        .iter()
+        .filter(|variant| submitted.iter().any(|s| s == variant.variant_name()))
+        .cloned()
+        .collect::<Vec<_>>();
+    config
+        .check(items.len())
+        .map_err(|err| FieldError::new(name, err))?;
+    Ok(S::from_items(items))
+}
+
+macro_rules! impl_form_for_set {
+    ($set:ident<E $(, $hasher:ident)?>: $($bound:path),+) => {
+        impl<E $(, $hasher)?> Form for $set<E $(, $hasher)?>
+        where
+            E: UnitEnum $(+ $bound)+,
+            $($hasher: BuildHasher + Default + Clone + Send + Sync + 'static,)?
+        {
+            fn view(
+                field: FieldConfiguration,
+                name: Name,
+                value: Option<Self>,
+                callback: Option<Callback<Result<Self, FormError>>>,
+            ) -> impl IntoView {
+                view_selection(field, name, value, callback, SelectionConfig::default())
+            }
+
+            fn view_summary(field: FieldConfiguration, name: Name, value: Self) -> impl IntoView {
+                view_selection_summary(field, name, value)
+            }
+        }
+
+        impl<E $(, $hasher)?> Validate for $set<E $(, $hasher)?>
+        where
+            E: UnitEnum $(+ $bound)+,
+            $($hasher: BuildHasher + Default + Clone + Send + Sync + 'static,)?
+        {
+            fn validate(&self, name: Name) -> Result<(), FormError> {
+                validate_selection(self, name, SelectionConfig::default())
+            }
+        }
+
+        impl<E $(, $hasher)?> FromFormFields for $set<E $(, $hasher)?>
+        where
+            E: UnitEnum $(+ $bound)+,
+            $($hasher: BuildHasher + Default + Clone + Send + Sync + 'static,)?
+        {
+            fn from_form_fields(fields: &FormFields, name: Name) -> Result<Self, FormError> {
+                selection_from_form_fields(fields, name, SelectionConfig::default())
+            }
+        }
+    };
+}
+
+impl_form_for_set!(HashSet<E, S>: Eq, Hash);
+impl_form_for_set!(BTreeSet<E>: Ord);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Color {
+        Red,
+        Green,
+        Blue,
+    }
+
+    impl UnitEnum for Color {
+        const VARIANTS: &'static [Self] = &[Color::Red, Color::Green, Color::Blue];
+
+        fn variant_name(&self) -> &'static str {
+            match self {
+                Color::Red => "Red",
+                Color::Green => "Green",
+                Color::Blue => "Blue",
+            }
+        }
+
+        fn label(&self) -> String {
+            self.variant_name().to_owned()
+        }
+    }
+
+    fn decode(body: &[u8], config: SelectionConfig) -> Result<Vec<Color>, FormError> {
+        let fields = FormFields::from_urlencoded(body);
+        selection_from_form_fields(&fields, Name::from("colors"), config)
+    }
+
+    #[test]
+    fn selection_in_declaration_order() {
+        let colors = decode(
+            b"colors=Blue&colors=Red&colors=Blue",
+            SelectionConfig::default(),
+        );
+        assert_eq!(colors.unwrap(), [Color::Red, Color::Blue]);
+        let colors = decode(b"", SelectionConfig::default());
+        assert_eq!(colors.unwrap(), []);
+    }
+
+    #[test]
+    fn selection_errors() {
+        let err = decode(b"colors=Red&colors=Pink", SelectionConfig::default()).unwrap_err();
+        let error = err.iter().next().unwrap();
+        assert_eq!(error.to_string(), "Invalid selection");
+        assert_eq!(error.raw_value(), Some("Pink"));
+
+        let config = SelectionConfig {
+            min: Some(2),
+            max: Some(2),
+            ..SelectionConfig::default()
+        };
+        assert!(decode(b"colors=Red", config).is_err());
+        assert!(decode(b"colors=Red&colors=Green", config).is_ok());
+        assert!(decode(b"colors=Red&colors=Green&colors=Blue", config).is_err());
+    }
+}
@@ -1,10 +1,14 @@
 use crate::{
-    components::{Checkbox, InputType, Section},
-    t,
+    components::{Checkbox, ErrorMessage, InputType, Section, SummaryField, SummarySection},
+    draft::Draft,
+    draft_value, persist_draft_value, t,
     types::FormType,
-    FieldError, Form, FormError, FormMessage, Name,
+    FieldContext, FieldError, Form, FormError, FormFields, FormMessage, FromFormFields, ItemLabel,
+    Name, Validate,
 };
-use leptos::prelude::*;
+use leptos::{context::Provider, prelude::*};
+use std::collections::HashSet;
+use thiserror::Error;
 use uuid::Uuid;
 
 macro_rules! impl_form_for_int {
@@ -42,6 +46,19 @@ impl FormType for String {
     const INPUT_TYPE: InputType = InputType::Text;
 }
 
+impl Validate for bool {
+    fn validate(&self, _name: Name) -> Result<(), FormError> {
+        Ok(())
+    }
+}
+
+impl FromFormFields for bool {
+    fn from_form_fields(fields: &FormFields, name: Name) -> Result<Self, FormError> {
+        // Unchecked checkboxes are not submitted at all
+        Ok(fields.get(&name).is_some_and(|value| value != "false"))
+    }
+}
+
 impl Form for bool {
     fn view(
         field: crate::FieldConfiguration,
@@ -54,6 +71,7 @@ impl Form for bool {
                 label=field.label.expect("No label provided")
                 description=field.description
                 name=name
+                validation_mode=field.validation_mode
                 value=value
                 callback={callback.map(|callback| Callback::new(move |v: Result<Self, FieldError>| {
                     callback.run(v.map_err(FormError::from));
@@ -63,6 +81,60 @@ impl Form for bool {
             />
         }
     }
+
+    fn view_summary(field: crate::FieldConfiguration, _name: Name, value: Self) -> impl IntoView {
+        view! {
+            <SummaryField
+                label=field.label
+                value=t(if value { FormMessage::Yes } else { FormMessage::No })
+                class=field.class
+                colspan=field.colspan
+            />
+        }
+    }
+}
+
+impl<T> Validate for Vec<T>
+where
+    T: Validate,
+{
+    fn validate(&self, name: Name) -> Result<(), FormError> {
+        let mut errors = Vec::new();
+        for (index, item) in self.iter().enumerate() {
+            if let Err(err) = item.validate(name.push_index(index)) {
+                errors.extend(err);
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(FormError::from(errors))
+        }
+    }
+}
+
+impl<T> FromFormFields for Vec<T>
+where
+    T: FromFormFields,
+{
+    fn from_form_fields(fields: &FormFields, name: Name) -> Result<Self, FormError> {
+        let mut items = Vec::new();
+        let mut errors = Vec::new();
+        // Indices may have gaps if items were removed before submitting
+        for index in fields.indices(&name) {
+            match T::from_form_fields(fields, name.push_index(index)) {
+                Ok(item) => items.push(item),
+                Err(err) => errors.extend(err),
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(items)
+        } else {
+            Err(FormError::from(errors))
+        }
+    }
 }
 
 impl<T> Form for Vec<T>
@@ -75,108 +147,479 @@ where
         value: Option<Self>,
         callback: Option<Callback<Result<Self, FormError>>>,
     ) -> impl IntoView {
-        #[derive(Clone)]
-        struct Child<T> {
-            id: Uuid,
-            value: Option<Result<T, FormError>>,
-        }
-
-        impl<T> Child<T> {
-            fn new() -> Self {
-                Self {
-                    id: Uuid::new_v4(),
-                    value: None,
-                }
-            }
+        let (min_items, max_items) = (field.min_items, field.max_items);
+        view_items(field, name, value, callback, min_items, max_items)
+    }
+
+    fn view_summary(field: crate::FieldConfiguration, name: Name, value: Self) -> impl IntoView {
+        view! {
+            <SummarySection name=name.clone() heading={field.label} class={field.class} colspan={field.colspan}>
+                { if value.is_empty() {
+                    view! { <SummaryField value=t(FormMessage::NotProvided) /> }.into_any()
+                } else {
+                    value.into_iter().enumerate().map(|(index, item)| {
+                        let label = ItemLabel::label(field.item_label.as_ref(), index, Some(&item));
+                        view! {
+                            <div class="array-item">
+                                {T::view_summary(crate::FieldConfiguration {
+                                    label: Some(label.into()),
+                                    ..Default::default()
+                                }, name.push_index(index), item)}
+                            </div>
+                        }
+                    }).collect::<Vec<_>>().into_any()
+                }}
+            </SummarySection>
         }
+    }
+}
 
-        impl<T> From<T> for Child<T> {
-            fn from(value: T) -> Self {
-                Self {
-                    id: Uuid::new_v4(),
-                    value: Some(Ok(value)),
-                }
-            }
+#[derive(Debug, Clone, Copy, Error, PartialEq, Eq, Hash)]
+pub enum ItemCountError {
+    #[error("At least {0} items are required")]
+    TooFew(usize),
+    #[error("At most {0} items are allowed")]
+    TooMany(usize),
+}
+
+/// Checks the number of items of a `Vec` field with the `min_items` or `max_items` attributes.
+pub fn validate_item_count(
+    count: usize,
+    name: &Name,
+    min: Option<usize>,
+    max: Option<usize>,
+) -> Result<(), FormError> {
+    match (min, max) {
+        (Some(min), _) if count < min => {
+            Err(FieldError::new(name.clone(), ItemCountError::TooFew(min)).into())
+        }
+        (_, Some(max)) if count > max => {
+            Err(FieldError::new(name.clone(), ItemCountError::TooMany(max)).into())
         }
+        _ => Ok(()),
+    }
+}
 
+#[derive(Clone)]
+pub(crate) struct Child<T> {
+    pub(crate) id: Uuid,
+    pub(crate) value: Option<Result<T, FormError>>,
+}
+
+impl<T> Child<T> {
+    pub(crate) fn new() -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            value: None,
+        }
+    }
+}
+
+impl<T> From<T> for Child<T> {
+    fn from(value: T) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            value: Some(Ok(value)),
+        }
+    }
+}
+
+/// The items of a list of `min` to `max` items, shared by the layouts of `Vec` fields.
+pub(crate) struct Items<T: Send + Sync + 'static> {
+    name: StoredValue<Name>,
+    pub(crate) children: RwSignal<Vec<Child<T>>>,
+    min: Option<usize>,
+    max: Option<usize>,
+    draft: Draft,
+    rearranged: StoredValue<bool>,
+    count_context: Option<FieldContext>,
+    count_result: Signal<Result<(), FormError>>,
+}
+
+impl<T: Send + Sync + 'static> Clone for Items<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Send + Sync + 'static> Copy for Items<T> {}
+
+impl<T> Items<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    pub(crate) fn new(
+        label: &TextProp,
+        name: Name,
+        value: Option<Vec<T>>,
+        callback: Option<Callback<Result<Vec<T>, FormError>>>,
+        min: Option<usize>,
+        max: Option<usize>,
+    ) -> Self {
+        // A restored draft only contains the number of items, the items restore their own values
         let children: RwSignal<Vec<Child<T>>> = RwSignal::new(
-            value
-                .map(|v| v.into_iter().map(Child::from).collect())
-                .unwrap_or_default(),
+            match draft_value(&name).and_then(|len| len.parse::<usize>().ok()) {
+                Some(len) => (0..len).map(|_| Child::new()).collect(),
+                None => value
+                    .map(|v| v.into_iter().map(Child::from).collect())
+                    .unwrap_or_default(),
+            },
         );
+        persist_draft_value(name.clone(), move || children.with(Vec::len).to_string());
+
+        let count_result = Signal::derive({
+            let name = name.clone();
+            move || validate_item_count(children.with(Vec::len), &name, min, max)
+        });
+        let count_context = (min.is_some() || max.is_some()).then(|| {
+            let count_context = FieldContext::register(name.clone());
+            count_context.label().set(Some(label.get().to_string()));
+            Effect::new(move |_| {
+                let error = count_result
+                    .get()
+                    .err()
+                    .and_then(|err| err.into_iter().next());
+                count_context.error().set(error);
+            });
+            count_context
+        });
 
         if let Some(callback) = callback {
             Effect::new(move |_| {
-                #[allow(clippy::manual_try_fold)]
-                let results: Result<Vec<T>, FormError> =
+                // Items that have not reported their value yet are pending, the list is only
+                // reported once all items are
+                let Some(values) = children.with(|children| {
                     children
-                        .get()
                         .iter()
-                        .cloned()
-                        .fold(Ok(Vec::new()), |acc, c| match (acc, c.value) {
-                            (val, None) => val,
-                            (Ok(mut vec), Some(Ok(ref val))) => {
-                                vec.push(val.clone());
+                        .map(|c| c.value.clone())
+                        .collect::<Option<Vec<_>>>()
+                }) else {
+                    return;
+                };
+
+                #[allow(clippy::manual_try_fold)]
+                let results: Result<Vec<T>, FormError> =
+                    values
+                        .into_iter()
+                        .fold(Ok(Vec::new()), |acc, value| match (acc, value) {
+                            (Ok(mut vec), Ok(val)) => {
+                                vec.push(val);
                                 Ok(vec)
                             }
-                            (Ok(_), Some(Err(e))) => Err(e),
-                            (Err(e), Some(Err(e2))) => {
-                                let mut combined = e;
-                                combined.extend(e2);
-                                Err(combined)
+                            (Ok(_), Err(e)) => Err(e),
+                            (Err(mut e), Err(e2)) => {
+                                e.extend(e2);
+                                Err(e)
                             }
-                            (Err(e), _) => Err(e),
+                            (Err(e), Ok(_)) => Err(e),
                         });
 
+                let results = match (results, count_result.get()) {
+                    (results, Ok(())) => results,
+                    (Ok(_), Err(e)) => Err(e),
+                    (Err(mut e), Err(e2)) => {
+                        e.extend(e2);
+                        Err(e)
+                    }
+                };
+
                 callback.run(results);
             });
         }
 
+        Items {
+            name: StoredValue::new(name),
+            children,
+            min,
+            max,
+            // Items keep their raw values when they are rearranged, also outside of a form
+            draft: use_context::<Draft>().unwrap_or_else(Draft::in_memory),
+            rearranged: StoredValue::new(false),
+            count_context,
+            count_result,
+        }
+    }
+
+    /// Rearranges the items, which are given with their index before rearranging, or `None`
+    /// for new items. Items rendered again at a new index restore their raw values from the
+    /// draft, which are moved along with them.
+    pub(crate) fn rearrange(&self, rearrange: impl FnOnce(&mut Vec<(Option<usize>, Child<T>)>)) {
+        self.rearrange_with(rearrange, |_| {});
+    }
+
+    /// Rearranges the items like [`Items::rearrange`], then updates the raw values of the
+    /// draft before the items are rendered again.
+    pub(crate) fn rearrange_with(
+        &self,
+        rearrange: impl FnOnce(&mut Vec<(Option<usize>, Child<T>)>),
+        update_draft: impl FnOnce(&mut FormFields),
+    ) {
+        let mut entries = self
+            .children
+            .get_untracked()
+            .into_iter()
+            .enumerate()
+            .map(|(index, child)| (Some(index), child))
+            .collect::<Vec<_>>();
+        rearrange(&mut entries);
+
+        let order = entries.iter().map(|(old, _)| *old).collect::<Vec<_>>();
+        self.name.with_value(|name| self.draft.reindex(name, &order));
+        self.draft.values().update(update_draft);
+        self.rearranged.set_value(true);
+        if let Some(count_context) = self.count_context {
+            count_context.touched().set(true);
+        }
+        self.children
+            .set(entries.into_iter().map(|(_, child)| child).collect());
+    }
+
+    /// The name of the item at `index`.
+    pub(crate) fn item_name(&self, index: usize) -> Name {
+        self.name.with_value(|name| name.push_index(index))
+    }
+
+    pub(crate) fn position(entries: &[(Option<usize>, Child<T>)], id: Uuid) -> Option<usize> {
+        entries.iter().position(|(_, child)| child.id == id)
+    }
+
+    pub(crate) fn can_add(&self) -> bool {
+        self.max
+            .is_none_or(|max| self.children.with(Vec::len) < max)
+    }
+
+    pub(crate) fn can_remove(&self) -> bool {
+        self.min
+            .is_none_or(|min| self.children.with(Vec::len) > min)
+    }
+
+    pub(crate) fn value(&self, id: Uuid) -> Option<Result<T, FormError>> {
+        self.children.with(|children| {
+            children
+                .iter()
+                .find(|c| c.id == id)
+                .and_then(|c| c.value.clone())
+        })
+    }
+
+    pub(crate) fn set_value(&self, id: Uuid, value: Result<T, FormError>) {
+        let mut children = self.children.write();
+        if let Some(pos) = children.iter().position(|c| c.id == id) {
+            children[pos].value = Some(value);
+        }
+    }
+
+    /// Creates the view of an item within a provider of the draft, which records the raw
+    /// values of its fields. After rearranging, the draft restores them.
+    pub(crate) fn view_item<V: IntoView + 'static>(
+        &self,
+        view_item: impl FnOnce() -> V + Send + 'static,
+    ) -> AnyView {
+        let draft = if self.rearranged.get_value() {
+            self.draft.restoring()
+        } else {
+            self.draft
+        };
         view! {
-            <Section name=name heading={field.label.expect("No label provided").clone()}>
-                <For
-                    each={move || children.get().into_iter().enumerate()}
-                    key={move |(_, child)| child.id}
-                    children={move |(index, child)| {
+            <Provider value=draft>
+                {view_item()}
+            </Provider>
+        }
+        .into_any()
+    }
 
-                        view! {
-                            <div class={format!("array-item item-{}", child.id)}>
-                                {T::view(crate::FieldConfiguration {
-                                    label: None,
-                                    description: None,
-                                    class: None,
-                                    colspan: None,
-                                    placeholder: None,
-                                }, name.push_index(index), child.value.and_then(|v| v.ok()), Some(Callback::new(move |v: Result<T, FormError>| {
-                                    let mut children = children.write();
-                                    if let Some(pos) = children.iter().position(|c| c.id == child.id) {
-                                        children[pos].value = Some(v);
-                                    }
-                                })))}
+    /// The error of a list with too few or too many items, if the number of items is limited.
+    pub(crate) fn view_count_error(&self) -> impl IntoView {
+        let count_result = self.count_result;
+        self.count_context.map(|count_context| {
+            view! {
+                <ErrorMessage touched={count_context.touched()} value={count_result} />
+            }
+        })
+    }
+}
+
+/// Renders the items of a list, which can be added, removed, duplicated and reordered
+/// within `min` and `max` items.
+pub(crate) fn view_items<T>(
+    field: crate::FieldConfiguration,
+    name: Name,
+    value: Option<Vec<T>>,
+    callback: Option<Callback<Result<Vec<T>, FormError>>>,
+    min: Option<usize>,
+    max: Option<usize>,
+) -> impl IntoView
+where
+    T: Form + Clone + Send + Sync + 'static,
+{
+    let label = field.label.expect("No label provided");
+    let items = Items::new(&label, name.clone(), value, callback, min, max);
+    let children = items.children;
+    let position = Items::<T>::position;
+
+    let dragged: StoredValue<Option<Uuid>> = StoredValue::new(None);
+    let collapsed: RwSignal<HashSet<Uuid>> = RwSignal::new(HashSet::new());
+    let item_label = StoredValue::new(field.item_label);
+    let validation_mode = field.validation_mode;
+
+    view! {
+        <Section name=name.clone() heading={label}>
+            <For
+                each={move || children.get().into_iter().enumerate()}
+                key={move |(index, child)| (child.id, *index)}
+                children={move |(index, child)| {
+                    let id = child.id;
+                    let item_value = Signal::derive(move || items.value(id));
+                    let item_label = Signal::derive(move || {
+                        item_label.with_value(|item_label| {
+                            item_value.with(|value| {
+                                let item = value.as_ref().and_then(|value| value.as_ref().ok());
+                                ItemLabel::label(item_label.as_ref(), index, item)
+                            })
+                        })
+                    });
+                    let item_view = items.view_item(move || T::view(crate::FieldConfiguration {
+                        label: Some(TextProp::from(move || item_label.get())),
+                        validation_mode,
+                        ..Default::default()
+                    }, items.item_name(index), child.value.and_then(|v| v.ok()), Some(Callback::new(move |v: Result<T, FormError>| {
+                        items.set_value(id, v);
+                    }))));
+                    let is_first = move || index == 0;
+                    let is_last = move || index + 1 == children.with(Vec::len);
+                    let is_collapsed = move || collapsed.with(|collapsed| collapsed.contains(&id));
+                    let is_invalid = move || item_value.with(|value| matches!(value, Some(Err(_))));
+
+                    // Collapsed items stay rendered, so their fields keep their values
+                    let collapsed_summary = move || {
+                        is_collapsed().then(|| {
+                            let summary = item_value.get().and_then(|value| value.ok()).map(|value| {
+                                T::view_summary(crate::FieldConfiguration::default(), items.item_name(index), value)
+                            });
+
+                            view! {
+                                <span class="array-item-label">{move || item_label.get()}</span>
+                                <span class="array-item-summary">{summary}</span>
+                                {is_invalid().then(|| view! {
+                                    <span class="array-item-error-indicator" role="img" aria-label=t(FormMessage::ItemErrorIndicator)>"!"</span>
+                                })}
+                            }
+                        })
+                    };
+
+                    view! {
+                        <div
+                            class={format!("array-item item-{}", id)}
+                            class:collapsed=is_collapsed
+                            class:error=move || is_collapsed() && is_invalid()
+                            on:dragover=move |ev| {
+                                if dragged.get_value().is_some() {
+                                    ev.prevent_default();
+                                }
+                            }
+                            on:drop=move |ev| {
+                                ev.prevent_default();
+                                if let Some(dragged_id) = dragged.get_value() {
+                                    dragged.set_value(None);
+                                    items.rearrange(|entries| {
+                                        if let (Some(from), Some(to)) = (position(entries, dragged_id), position(entries, id)) {
+                                            let entry = entries.remove(from);
+                                            entries.insert(to, entry);
+                                        }
+                                    });
+                                }
+                            }
+                        >
+                            <div class="array-item-header">
+                                <span
+                                    class="array-drag-handle"
+                                    draggable="true"
+                                    on:dragstart=move |_| dragged.set_value(Some(id))
+                                    on:dragend=move |_| dragged.set_value(None)
+                                ></span>
                                 <button
                                     type="button"
-                                    class="array-remove-button"
-                                    on:click={move |_| {
-                                        children.update(move |children| {
-                                            children.retain(|c| c.id != child.id);
-                                        });
-                                    }}
-                                >{t(FormMessage::RemoveButton)}</button>
+                                    class="array-collapse-button"
+                                    aria-expanded=move || (!is_collapsed()).to_string()
+                                    on:click=move |_| collapsed.update(|collapsed| {
+                                        if !collapsed.remove(&id) {
+                                            collapsed.insert(id);
+                                        }
+                                    })
+                                >{move || t(if is_collapsed() { FormMessage::ExpandButton } else { FormMessage::CollapseButton })}</button>
+                                {collapsed_summary}
                             </div>
-                        }
-                    }}
-                />
+                            <div class="array-item-content" hidden=is_collapsed>
+                                {item_view}
+                            </div>
+                            <div class="array-item-actions">
+                                <button
+                                    type="button"
+                                    class="array-move-up-button"
+                                    disabled=is_first
+                                    on:click=move |_| items.rearrange(|entries| {
+                                        if let Some(pos) = position(entries, id).filter(|&pos| pos > 0) {
+                                            entries.swap(pos - 1, pos);
+                                        }
+                                    })
+                                >{t(FormMessage::MoveUpButton)}</button>
+                                <button
+                                    type="button"
+                                    class="array-move-down-button"
+                                    disabled=is_last
+                                    on:click=move |_| items.rearrange(|entries| {
+                                        if let Some(pos) = position(entries, id).filter(|&pos| pos + 1 < entries.len()) {
+                                            entries.swap(pos, pos + 1);
+                                        }
+                                    })
+                                >{t(FormMessage::MoveDownButton)}</button>
+                                {move || items.can_add().then(|| view! {
+                                    <button
+                                        type="button"
+                                        class="array-duplicate-button"
+                                        on:click=move |_| items.rearrange(|entries| {
+                                            if let Some(pos) = position(entries, id) {
+                                                let (old, child) = entries[pos].clone();
+                                                let duplicate = Child { id: Uuid::new_v4(), value: child.value };
+                                                entries.insert(pos + 1, (old, duplicate));
+                                            }
+                                        })
+                                    >{t(FormMessage::DuplicateButton)}</button>
+                                    <button
+                                        type="button"
+                                        class="array-insert-button"
+                                        on:click=move |_| items.rearrange(|entries| {
+                                            if let Some(pos) = position(entries, id) {
+                                                entries.insert(pos, (None, Child::new()));
+                                            }
+                                        })
+                                    >{t(FormMessage::InsertAboveButton)}</button>
+                                })}
+                                {move || items.can_remove().then(|| view! {
+                                    <button
+                                        type="button"
+                                        class="array-remove-button"
+                                        on:click=move |_| items.rearrange(|entries| {
+                                            entries.retain(|(_, child)| child.id != id);
+                                        })
+                                    >{t(FormMessage::RemoveButton)}</button>
+                                })}
+                            </div>
+                        </div>
+                    }
+                }}
+            />
+            {move || items.can_add().then(|| view! {
                 <button
                     type="button"
                     class="array-add-button"
-                    on:click={move |_| {
-                        children.update(move |children| {
-                            children.push(Child::new());
-                        });
-                    }}
+                    on:click=move |_| items.rearrange(|entries| {
+                        entries.push((None, Child::new()));
+                    })
                 >{t(FormMessage::AddButton)}</button>
-            </Section>
-        }
+            })}
+            {items.view_count_error()}
+        </Section>
     }
 }
 
@@ -206,3 +649,27 @@ where
     }
 }
 */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_item_count_within_bounds() {
+        let name = Name::from("list");
+        assert!(validate_item_count(0, &name, None, None).is_ok());
+        assert!(validate_item_count(2, &name, Some(2), Some(2)).is_ok());
+        assert!(validate_item_count(5, &name, Some(1), None).is_ok());
+    }
+
+    #[test]
+    fn validate_item_count_errors() {
+        let name = Name::from("list");
+        let err = validate_item_count(1, &name, Some(2), Some(4)).unwrap_err();
+        assert_eq!(err.len(), 1);
+        assert_eq!(err.iter().next().unwrap().name(), name);
+        assert_eq!(err.to_string().trim(), "At least 2 items are required");
+        let err = validate_item_count(5, &name, Some(2), Some(4)).unwrap_err();
+        assert_eq!(err.to_string().trim(), "At most 4 items are allowed");
+    }
+}
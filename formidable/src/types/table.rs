@@ -0,0 +1,213 @@
+use leptos::{
+    context::Provider,
+    prelude::*,
+    wasm_bindgen::JsCast,
+    web_sys::{ClipboardEvent, Element},
+};
+
+use crate::{
+    components::{HiddenLabels, Section},
+    t,
+    types::std_types::{Child, Items},
+    FieldConfiguration, Form, FormError, FormMessage, Name,
+};
+
+/// A column of a table, showing a field of the rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TableColumn {
+    /// The key of the field.
+    pub key: &'static str,
+    /// Whether the field is a single input, which can be filled by pasting into the table.
+    pub pastable: bool,
+}
+
+/// Structs whose fields can be rendered as the cells of a table row, see [`view_table`].
+///
+/// Implemented by `#[derive(Form)]` for structs with named fields.
+pub trait TableRow: Form {
+    /// The columns of the fields, in order.
+    const COLUMNS: &'static [TableColumn];
+
+    /// The labels of the fields, shown in the header row.
+    fn column_labels() -> Vec<String>;
+
+    /// Renders a cell for each field of the row at `name`.
+    fn view_row(
+        name: Name,
+        value: Option<Self>,
+        callback: Option<Callback<Result<Self, FormError>>>,
+    ) -> impl IntoView;
+}
+
+/// Splits tab-separated clipboard data, e.g. copied from a spreadsheet, into rows of cells.
+fn parse_clipboard(text: &str) -> Vec<Vec<String>> {
+    text.lines()
+        .map(|line| line.split('\t').map(str::to_owned).collect())
+        .collect()
+}
+
+/// The index stored in the `attribute` of the closest ancestor of `element` matching `selector`.
+fn closest_index(element: &Element, selector: &str, attribute: &str) -> Option<usize> {
+    element
+        .closest(selector)
+        .ok()
+        .flatten()
+        .and_then(|element| element.get_attribute(attribute))
+        .and_then(|index| index.parse().ok())
+}
+
+/// Renders a list of structs as a table with a row per item and a column per field.
+///
+/// Used for fields with `render_as = "table"`. Rows can be added and removed within
+/// `min_items` and `max_items`, and tab-separated data pasted into the table fills the rows
+/// and columns from the cell it is pasted into, adding rows as needed. Only columns of single
+/// inputs are filled, see [`Form::SINGLE_INPUT`]. The labels of the cells are only kept for
+/// screen readers, see [`HiddenLabels`].
+pub fn view_table<T>(
+    field: FieldConfiguration,
+    name: Name,
+    value: Option<Vec<T>>,
+    callback: Option<Callback<Result<Vec<T>, FormError>>>,
+) -> impl IntoView
+where
+    T: TableRow + Clone + Send + Sync + 'static,
+{
+    let label = field.label.expect("No label provided");
+    let max = field.max_items;
+    let items = Items::new(&label, name.clone(), value, callback, field.min_items, max);
+    let children = items.children;
+
+    let paste = move |ev: ClipboardEvent| {
+        let Some(text) = ev
+            .clipboard_data()
+            .and_then(|data| data.get_data("text/plain").ok())
+        else {
+            return;
+        };
+        let rows = parse_clipboard(&text);
+
+        // A single value is pasted into the focused input as usual
+        if rows.len() < 2 && rows.iter().all(|cells| cells.len() < 2) {
+            return;
+        }
+        ev.prevent_default();
+
+        let target = ev
+            .target()
+            .and_then(|target| target.dyn_into::<Element>().ok());
+        let len = children.with_untracked(Vec::len);
+        let start_row = target
+            .as_ref()
+            .and_then(|target| closest_index(target, "tr[data-row]", "data-row"))
+            .unwrap_or(len);
+        let start_column = target
+            .as_ref()
+            .and_then(|target| closest_index(target, "td[data-column]", "data-column"))
+            .unwrap_or(0);
+        let end_row = max.map_or(start_row + rows.len(), |max| {
+            (start_row + rows.len()).min(max.max(len))
+        });
+        let row_count = end_row.saturating_sub(start_row);
+
+        items.rearrange_with(
+            |entries| {
+                while entries.len() < end_row {
+                    entries.push((None, Child::new()));
+                }
+                // Filled rows are rendered again to show the pasted values
+                for (_, child) in entries.iter_mut().skip(start_row).take(row_count) {
+                    *child = Child::new();
+                }
+            },
+            |values| {
+                for (index, cells) in rows.iter().take(row_count).enumerate() {
+                    let row = items.item_name(start_row + index);
+                    // Cells of other columns are skipped, their raw values are not named
+                    // after the field
+                    let columns = T::COLUMNS.iter().skip(start_column).zip(cells);
+                    for (column, cell) in columns.filter(|(column, _)| column.pastable) {
+                        values.set(&row.push_key(column.key), cell.as_str());
+                    }
+                }
+            },
+        );
+    };
+
+    view! {
+        <Section name=name.clone() heading={label} description={field.description} class={field.class} colspan={field.colspan}>
+            <table class="form-table" on:paste=paste>
+                <thead>
+                    <tr>
+                        {T::column_labels().into_iter().map(|label| view! {
+                            <th scope="col">{label}</th>
+                        }).collect_view()}
+                        <th class="table-actions"></th>
+                    </tr>
+                </thead>
+                <tbody>
+                    <For
+                        each={move || children.get().into_iter().enumerate()}
+                        key={move |(index, child)| (child.id, *index)}
+                        children={move |(index, child)| {
+                            let id = child.id;
+                            // The columns are labelled by the header row instead of every cell
+                            let row_view = items.view_item(move || view! {
+                                <Provider value=HiddenLabels>
+                                    {T::view_row(
+                                        items.item_name(index),
+                                        child.value.and_then(|v| v.ok()),
+                                        Some(Callback::new(move |v: Result<T, FormError>| {
+                                            items.set_value(id, v);
+                                        })),
+                                    )}
+                                </Provider>
+                            });
+
+                            view! {
+                                <tr class="table-row" data-row=index>
+                                    {row_view}
+                                    <td class="table-actions">
+                                        {move || items.can_remove().then(|| view! {
+                                            <button
+                                                type="button"
+                                                class="array-remove-button"
+                                                on:click=move |_| items.rearrange(|entries| {
+                                                    entries.retain(|(_, child)| child.id != id);
+                                                })
+                                            >{t(FormMessage::RemoveButton)}</button>
+                                        })}
+                                    </td>
+                                </tr>
+                            }
+                        }}
+                    />
+                </tbody>
+            </table>
+            {move || items.can_add().then(|| view! {
+                <button
+                    type="button"
+                    class="array-add-button"
+                    on:click=move |_| items.rearrange(|entries| {
+                        entries.push((None, Child::new()));
+                    })
+                >{t(FormMessage::AddButton)}</button>
+            })}
+            {items.view_count_error()}
+        </Section>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_clipboard_splits_rows_and_cells() {
+        assert_eq!(
+            parse_clipboard("a\tb\r\nc\t\n"),
+            [vec!["a", "b"], vec!["c", ""]]
+        );
+        assert_eq!(parse_clipboard("single"), [vec!["single"]]);
+        assert_eq!(parse_clipboard(""), Vec::<Vec<String>>::new());
+    }
+}
@@ -7,6 +7,7 @@ use crate::{components::InputType, types::FormType};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Display, Into, Deref)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "String"))]
 pub struct Tel(String);
 
 #[derive(Debug, Clone, Copy, Error, PartialEq, Eq, Hash)]
@@ -36,6 +37,14 @@ impl FromStr for Tel {
     }
 }
 
+impl TryFrom<String> for Tel {
+    type Error = TelError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
 impl FormType for Tel {
     const INPUT_TYPE: InputType = InputType::Tel;
     const REQUIRED: Option<bool> = Some(true);
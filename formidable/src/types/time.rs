@@ -1,6 +1,7 @@
 use crate::{
-    components::{Input, InputType},
-    FieldError, Form, FormError, Name,
+    components::{Input, InputType, SummaryField},
+    types::{parse_form_field, validate_from_str},
+    FieldError, Form, FormError, FormFields, FromFormFields, Name, Validate,
 };
 use derive_more::{Deref, Into};
 use leptos::prelude::*;
@@ -38,6 +39,18 @@ impl Default for Date {
     }
 }
 
+impl Validate for Date {
+    fn validate(&self, name: Name) -> Result<(), FormError> {
+        validate_from_str(self, name)
+    }
+}
+
+impl FromFormFields for Date {
+    fn from_form_fields(fields: &FormFields, name: Name) -> Result<Self, FormError> {
+        parse_form_field(fields, name)
+    }
+}
+
 impl Form for Date {
     fn view(
         field: crate::FieldConfiguration,
@@ -50,6 +63,7 @@ impl Form for Date {
                 label=field.label.expect("No label provided")
                 description=field.description
                 name=name
+                validation_mode=field.validation_mode
                 value=value
                 callback={callback.map(|callback| Callback::new(move |v: Result<Self, FieldError>| {
                     callback.run(v.map_err(FormError::from));
@@ -58,6 +72,10 @@ impl Form for Date {
             />
         }
     }
+
+    fn view_summary(field: crate::FieldConfiguration, _name: Name, value: Self) -> impl IntoView {
+        view! { <SummaryField label=field.label value=value.to_string() /> }
+    }
 }
 
 const DATETIME_LOCAL_FORMAT: &[time::format_description::BorrowedFormatItem<'_>] =
@@ -90,6 +108,18 @@ impl Default for PrimitiveDateTime {
     }
 }
 
+impl Validate for PrimitiveDateTime {
+    fn validate(&self, name: Name) -> Result<(), FormError> {
+        validate_from_str(self, name)
+    }
+}
+
+impl FromFormFields for PrimitiveDateTime {
+    fn from_form_fields(fields: &FormFields, name: Name) -> Result<Self, FormError> {
+        parse_form_field(fields, name)
+    }
+}
+
 impl Form for PrimitiveDateTime {
     fn view(
         field: crate::FieldConfiguration,
@@ -102,6 +132,7 @@ impl Form for PrimitiveDateTime {
                 label=field.label.expect("No label provided")
                 description=field.description
                 name=name
+                validation_mode=field.validation_mode
                 value=value
                 callback={callback.map(|callback| Callback::new(move |v: Result<Self, FieldError>| {
                     callback.run(v.map_err(FormError::from));
@@ -110,6 +141,10 @@ impl Form for PrimitiveDateTime {
             />
         }
     }
+
+    fn view_summary(field: crate::FieldConfiguration, _name: Name, value: Self) -> impl IntoView {
+        view! { <SummaryField label=field.label value=value.to_string() /> }
+    }
 }
 
 const TIME_FORMAT: &[time::format_description::BorrowedFormatItem<'_>] =
@@ -142,6 +177,18 @@ impl Default for Time {
     }
 }
 
+impl Validate for Time {
+    fn validate(&self, name: Name) -> Result<(), FormError> {
+        validate_from_str(self, name)
+    }
+}
+
+impl FromFormFields for Time {
+    fn from_form_fields(fields: &FormFields, name: Name) -> Result<Self, FormError> {
+        parse_form_field(fields, name)
+    }
+}
+
 impl Form for Time {
     fn view(
         field: crate::FieldConfiguration,
@@ -154,6 +201,7 @@ impl Form for Time {
                 label=field.label.expect("No label provided")
                 description=field.description
                 name=name
+                validation_mode=field.validation_mode
                 value=value
                 callback={callback.map(|callback| Callback::new(move |v: Result<Self, FieldError>| {
                     callback.run(v.map_err(FormError::from));
@@ -162,4 +210,8 @@ impl Form for Time {
             />
         }
     }
+
+    fn view_summary(field: crate::FieldConfiguration, _name: Name, value: Self) -> impl IntoView {
+        view! { <SummaryField label=field.label value=value.to_string() /> }
+    }
 }